@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use hexx::*;
+
+pub fn layout_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Hex Layout");
+    group.significance_level(0.1).sample_size(100);
+    let dist: u32 = 100;
+    let layout = HexLayout::pointy().with_scale(Vec2::new(2.0, 3.0));
+
+    group.bench_with_input(BenchmarkId::new("Hex Corners", dist), &dist, |b, dist| {
+        b.iter(|| {
+            Hex::range(black_box(Hex::ZERO), *dist)
+                .map(|h| layout.hex_corners(h))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_with_input(
+        BenchmarkId::new("Cached Corner Offsets", dist),
+        &dist,
+        |b, dist| {
+            b.iter(|| {
+                let offsets = layout.corner_offsets();
+                Hex::range(black_box(Hex::ZERO), *dist)
+                    .map(|h| {
+                        let center = layout.hex_to_world_pos(h);
+                        offsets.map(|o| o + center)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        },
+    );
+    let positions: Vec<Vec2> = Hex::ZERO
+        .range(dist)
+        .map(|h| layout.hex_to_world_pos(h) * 0.7)
+        .collect();
+    group.bench_with_input(
+        BenchmarkId::new("World Pos To Hex", dist),
+        &positions,
+        |b, positions| {
+            b.iter(|| {
+                black_box(positions)
+                    .iter()
+                    .map(|p| layout.world_pos_to_hex(*p))
+                    .collect::<Vec<_>>()
+            })
+        },
+    );
+    group.bench_with_input(
+        BenchmarkId::new("Batch World Pos To Hex", dist),
+        &positions,
+        |b, positions| b.iter(|| layout.world_positions_to_hexes(black_box(positions))),
+    );
+    group.finish();
+}
+
+criterion_group!(benches, layout_benchmark);
+criterion_main!(benches);
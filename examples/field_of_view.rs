@@ -131,9 +131,12 @@ fn handle_input(
                 .entity(*entity)
                 .insert(MeshMaterial2d(grid.default_mat.clone_weak()));
         }
-        let fov = range_fov(hex_pos, FOV_RADIUS, |h| {
-            grid.blocked_coords.contains(&h) || h.ulength() > MAP_RADIUS
-        });
+        let fov = range_fov(
+            hex_pos,
+            FOV_RADIUS,
+            Some(&HexBounds::from_radius(MAP_RADIUS)),
+            |h| grid.blocked_coords.contains(&h),
+        );
         let entities: HashSet<_> = fov
             .into_iter()
             .filter_map(|h| grid.entities.get(&h).copied())
@@ -92,21 +92,20 @@ fn setup_grid(
         // Retrieve the local children coordinates (can be cached)
         let children = Hex::ZERO.range(settings.chunk_radius);
         // We compute the merged mesh with all children columns
-        let mesh = children.fold(MeshInfo::default(), |mut mesh, c| {
+        // Large chunks exceed `u16::MAX` vertices so we use `u32` indices
+        let mesh = MeshInfo32::merge_all(children.map(|c| {
             let [min, max] = settings.column_heights;
             let height = if min < max {
                 rng.gen_range(min..=max)
             } else {
                 min
             };
-            let info = ColumnMeshBuilder::new(&layout, height)
+            ColumnMeshBuilder::new(&layout, height)
                 .at(c)
                 .without_bottom_face()
                 .center_aligned()
-                .build();
-            mesh.merge_with(info);
-            mesh
-        });
+                .build()
+        }));
         let mesh = meshes.add(hex_mesh(mesh));
         commands
             .spawn((
@@ -121,7 +120,7 @@ fn setup_grid(
 }
 
 /// Compute a bevy mesh from a hexx mesh
-fn hex_mesh(mesh_info: MeshInfo) -> Mesh {
+fn hex_mesh(mesh_info: MeshInfo32) -> Mesh {
     Mesh::new(
         PrimitiveTopology::TriangleList,
         RenderAssetUsages::RENDER_WORLD,
@@ -129,7 +128,7 @@ fn hex_mesh(mesh_info: MeshInfo) -> Mesh {
     .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, mesh_info.vertices)
     .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, mesh_info.normals)
     .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, mesh_info.uvs)
-    .with_inserted_indices(Indices::U16(mesh_info.indices))
+    .with_inserted_indices(Indices::U32(mesh_info.indices))
 }
 
 impl Default for MapSettings {
@@ -244,7 +244,7 @@ fn setup(
     let layout = HexLayout::default();
     let mesh = ColumnMeshBuilder::new(&layout, params.height)
         .with_subdivisions(params.subdivisions)
-        .with_offset(Vec3::NEG_Y * params.height / 2.0)
+        .centered_pivot()
         .build();
     let mesh_handle = meshes.add(compute_mesh(mesh));
     let material_handle = materials.add(StandardMaterial {
@@ -310,7 +310,7 @@ fn update_mesh(params: Res<BuilderParams>, info: Res<HexInfo>, mut meshes: ResMu
     }
     let mut new_mesh = ColumnMeshBuilder::new(&info.layout, params.height)
         .with_subdivisions(params.subdivisions)
-        .with_offset(Vec3::NEG_Y * params.height / 2.0 * params.scale.y)
+        .centered_pivot()
         .with_scale(params.scale)
         .with_caps_uv_options(params.caps_uvs)
         .with_multi_custom_sides_options(match params.sides_edit_mode {
@@ -0,0 +1,32 @@
+use crate::Hex;
+use glam::Vec2;
+
+/// Computes the weighted centroid (*center of mass*) of the given coordinates
+/// and their weights, as fractional axial coordinates.
+///
+/// The result can be rounded to a [`Hex`] with [`Hex::round`], and the
+/// fractional cubic `z` coordinate is `-x - y`.
+///
+/// Returns `None` if `items` is empty or if the total weight is zero
+///
+/// # Examples
+///
+/// - Find the center of an empire weighted by tile population
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::weighted_centroid;
+///
+/// let population = [(hex(0, 0), 10.0), (hex(4, 0), 30.0), (hex(-2, 6), 0.0)];
+/// let centroid = weighted_centroid(population.into_iter()).unwrap();
+/// assert_eq!(centroid, [3.0, 0.0]);
+/// assert_eq!(Hex::round(centroid), hex(3, 0));
+///
+/// assert!(weighted_centroid(std::iter::empty()).is_none());
+/// ```
+pub fn weighted_centroid(items: impl Iterator<Item = (Hex, f32)>) -> Option<[f32; 2]> {
+    let (sum, total) = items.fold((Vec2::ZERO, 0.0), |(sum, total), (hex, weight)| {
+        (sum + hex.as_vec2() * weight, total + weight)
+    });
+    (total != 0.0).then(|| (sum / total).to_array())
+}
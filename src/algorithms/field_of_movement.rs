@@ -1,5 +1,8 @@
-use crate::Hex;
-use std::collections::{HashMap, HashSet};
+use crate::{
+    hex::{BuildHexHasher, HexHashMap},
+    Hex,
+};
+use std::collections::HashSet;
 
 /// Computes a field of movement around `coord` given a `budget`
 ///
@@ -17,7 +20,8 @@ use std::collections::{HashMap, HashSet};
 /// # Warning
 ///
 /// The implementation of this function is pretty naive and has a high
-/// complexity. It is not suitable for production use
+/// complexity. It is not suitable for production use, see
+/// [`reachable`](super::reachable) for a *Dijkstra* based alternative.
 ///
 /// # Examples
 ///
@@ -62,7 +66,10 @@ pub fn field_of_movement(
     budget: u32,
     cost: impl Fn(Hex) -> Option<u32>,
 ) -> HashSet<Hex> {
-    let mut computed_costs = HashMap::with_capacity(Hex::range_count(budget) as usize);
+    let mut computed_costs = HexHashMap::with_capacity_and_hasher(
+        Hex::range_count(budget) as usize,
+        BuildHexHasher::default(),
+    );
     computed_costs.insert(coord, 0);
 
     // We cache the rings and costs
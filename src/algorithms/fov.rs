@@ -1,4 +1,7 @@
-use crate::{EdgeDirection, Hex};
+use crate::{
+    hex::{BuildHexHasher, HexHashMap},
+    DirectionMask, EdgeDirection, Hex, HexBounds,
+};
 use std::collections::HashSet;
 
 /// Computes a field of view around `coord` in a given `range`
@@ -6,6 +9,10 @@ use std::collections::HashSet;
 /// This algorithm takes in account coordinates *visibility* through the
 /// `blocking` argument. (*Blocking* coordinates should return `true`)
 ///
+/// If `bounds` are provided, coordinates outside of them are ignored: they
+/// are never visible and don't block the view. The `range` is clamped to the
+/// bounds and an empty set is returned if `coord` is out of bounds.
+///
 /// # Examples
 ///
 /// - Compute field of view with no boundaries and some blocking tiles
@@ -21,15 +28,193 @@ use std::collections::HashSet;
 /// // Add blocking coordinates
 /// // blocking_coords.insert(hex(2, 0));
 /// // ..
-/// let fov = range_fov(pos, range, |h| blocking_coords.contains(&h));
+/// let fov = range_fov(pos, range, None, |h| blocking_coords.contains(&h));
 /// ```
-pub fn range_fov(coord: Hex, range: u32, blocking: impl Fn(Hex) -> bool) -> HashSet<Hex> {
+///
+/// - Compute field of view in a bounded map
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::range_fov;
+///
+/// let bounds = HexBounds::from_radius(5);
+/// let fov = range_fov(hex(3, 0), 10, Some(&bounds), |_| false);
+/// assert!(fov.iter().all(|h| bounds.is_in_bounds(*h)));
+/// assert_eq!(fov.len(), bounds.hex_count());
+/// ```
+pub fn range_fov(
+    coord: Hex,
+    range: u32,
+    bounds: Option<&HexBounds>,
+    blocking: impl Fn(Hex) -> bool,
+) -> HashSet<Hex> {
+    let range = match bounds {
+        Some(b) if !b.is_in_bounds(coord) => return HashSet::new(),
+        Some(b) => range.min(coord.unsigned_distance_to(b.center) + b.radius),
+        None => range,
+    };
+    // Bounds are convex, so a line leaving them never comes back in
+    let in_bounds = |h: &Hex| bounds.is_none_or(|b| b.is_in_bounds(*h));
     coord
         .ring(range)
-        .flat_map(|target| coord.line_to(target).take_while(|h| !blocking(*h)))
+        .flat_map(|target| {
+            coord
+                .line_to(target)
+                .take_while(|h| in_bounds(h) && !blocking(*h))
+        })
         .collect()
 }
 
+/// Checks if `from` can see `to`, walking the [`Hex::line_to`] line between
+/// them and stopping at the first *blocking* coordinate (returning `true`
+/// for `blocking`).
+///
+/// This is much cheaper than checking membership in a [`range_fov`] when
+/// only a single target matters.
+///
+/// If `include_endpoints` is `false`, `from` and `to` are not checked for
+/// blocking, which allows to check the visibility of a wall or from inside
+/// a blocking tile. If `from == to`, the result is always `true`.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::line_of_sight;
+///
+/// let wall = hex(2, 0);
+/// let blocking = |h| h == wall;
+/// assert!(line_of_sight(hex(0, 0), hex(1, 3), false, blocking));
+/// assert!(!line_of_sight(hex(0, 0), hex(4, 0), false, blocking));
+/// // The wall itself is visible, unless it blocks its own visibility
+/// assert!(line_of_sight(hex(0, 0), wall, false, blocking));
+/// assert!(!line_of_sight(hex(0, 0), wall, true, blocking));
+/// ```
+#[doc(alias = "los")]
+pub fn line_of_sight(
+    from: Hex,
+    to: Hex,
+    include_endpoints: bool,
+    blocking: impl Fn(Hex) -> bool,
+) -> bool {
+    if from == to {
+        return true;
+    }
+    let line = from.line_to(to);
+    // The line has at least 2 coordinates
+    let (skip, len) = if include_endpoints {
+        (0, line.len())
+    } else {
+        (1, line.len() - 2)
+    };
+    line.skip(skip).take(len).all(|h| !blocking(h))
+}
+
+/// Computes a field of view around `coord` in a given `range`, along with the
+/// directions through which each visible coordinate is reached by the view.
+///
+/// Like [`range_fov`], *blocking* coordinates (returning `true` for
+/// `blocking`) are not visible and stop the view. For every visible
+/// coordinate, the returned [`DirectionMask`] contains the directions of the
+/// neighbors the lines of sight come from. A coordinate seen from a single
+/// side can then be distinguished from a fully surrounded one, for example
+/// to render soft fog of war shadows.
+///
+/// `coord` itself is included with an empty mask, unless it is blocking.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::range_fov_with_directions;
+///
+/// let fov = range_fov_with_directions(hex(0, 0), 5, |h| h == hex(2, 0));
+/// assert!(fov[&hex(0, 0)].is_empty());
+/// // Direct neighbors are seen from the origin only
+/// let dir = EdgeDirection::from_hex_step(hex(-1, 0)).unwrap();
+/// assert_eq!(fov[&hex(1, 0)], DirectionMask::from_direction(dir));
+/// assert!(!fov.contains_key(&hex(2, 0)));
+/// ```
+pub fn range_fov_with_directions(
+    coord: Hex,
+    range: u32,
+    blocking: impl Fn(Hex) -> bool,
+) -> HexHashMap<DirectionMask> {
+    let mut res = HexHashMap::with_capacity_and_hasher(
+        Hex::range_count(range) as usize,
+        BuildHexHasher::default(),
+    );
+    if blocking(coord) {
+        return res;
+    }
+    res.insert(coord, DirectionMask::EMPTY);
+    for target in coord.ring(range) {
+        let mut previous = coord;
+        for hex in coord.line_to(target).skip(1) {
+            if blocking(hex) {
+                break;
+            }
+            let mask = res.entry(hex).or_default();
+            if let Some(dir) = EdgeDirection::from_hex_step(previous - hex) {
+                *mask |= dir;
+            }
+            previous = hex;
+        }
+    }
+    res
+}
+
+/// Computes a field of view around `coord` in a given `range` with *cover*
+/// levels
+///
+/// Unlike [`range_fov`] which only handles fully blocking coordinates, every
+/// coordinate may partially obstruct the view through the `cover_fn` argument:
+/// `0` means no obstruction and [`u8::MAX`] means a fully blocking coordinate.
+///
+/// Cover accumulates (saturating) along each line of sight, a coordinate does
+/// not provide cover to itself. The effective cover of a coordinate is the
+/// lowest accumulated cover among all lines reaching it, coordinates with a
+/// [`u8::MAX`] effective cover are not visible and not included in the
+/// returned map.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::range_fov_with_cover;
+///
+/// let wall = hex(2, 0);
+/// let fov = range_fov_with_cover(hex(0, 0), 4, |h| if h == wall { 1 } else { 0 });
+/// // Nothing hides the wall
+/// assert_eq!(fov[&wall], 0);
+/// // The coordinate behind the wall is in partial cover
+/// assert_eq!(fov[&hex(3, 0)], 1);
+/// ```
+pub fn range_fov_with_cover(
+    coord: Hex,
+    range: u32,
+    cover_fn: impl Fn(Hex) -> u8,
+) -> HexHashMap<u8> {
+    let mut res = HexHashMap::with_capacity_and_hasher(
+        Hex::range_count(range) as usize,
+        BuildHexHasher::default(),
+    );
+    res.insert(coord, 0);
+    for target in coord.ring(range) {
+        let mut cover = 0_u8;
+        for hex in coord.line_to(target).skip(1) {
+            res.entry(hex)
+                .and_modify(|c: &mut u8| *c = (*c).min(cover))
+                .or_insert(cover);
+            cover = cover.saturating_add(cover_fn(hex));
+            if cover == u8::MAX {
+                break;
+            }
+        }
+    }
+    res
+}
+
 /// Computes a field of view around `coord` in a given `range` towards
 /// `direction` with 120 degrees vision
 ///
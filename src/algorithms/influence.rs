@@ -0,0 +1,49 @@
+use crate::{hex::HexHashMap, Hex};
+
+/// Spreads the `sources` values over the grid, decaying with the distance.
+///
+/// For each source, every coordinate within `radius` receives
+/// `value * decay^distance`. Values of overlapping sources are accumulated.
+///
+/// The returned map only contains the affected coordinates. This is a
+/// classic AI technique to build threat or desirability maps.
+///
+/// # Arguments
+///
+/// * `sources` - the influence sources, as coordinates and values
+/// * `radius` - the maximum spreading distance around each source
+/// * `decay` - the factor applied to the value for every distance unit, usually
+///   in `0.0..=1.0`
+///
+/// # Example
+///
+/// - Compute a threat map from enemy units
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::influence_map;
+///
+/// let enemies = [(hex(0, 0), 10.0), (hex(2, 0), 4.0)];
+/// let threat = influence_map(enemies.into_iter(), 3, 0.5);
+/// assert_eq!(threat[&hex(0, 0)], 10.0 + 4.0 * 0.25);
+/// assert_eq!(threat[&hex(1, 0)], 5.0 + 2.0);
+/// assert!(!threat.contains_key(&hex(-4, 0)));
+/// ```
+pub fn influence_map(
+    sources: impl Iterator<Item = (Hex, f32)>,
+    radius: u32,
+    decay: f32,
+) -> HexHashMap<f32> {
+    // Precomputed decay factor for each distance
+    let factors: Vec<f32> = std::iter::successors(Some(1.0), |f| Some(f * decay))
+        .take(radius as usize + 1)
+        .collect();
+    let mut map = HexHashMap::default();
+    for (source, value) in sources {
+        for coord in source.spiral_range(0..=radius) {
+            let dist = source.unsigned_distance_to(coord) as usize;
+            *map.entry(coord).or_default() += value * factors[dist];
+        }
+    }
+    map
+}
@@ -1,7 +1,18 @@
+mod centroid;
 mod field_of_movement;
 mod fov;
+mod influence;
 mod pathfinding;
+mod region;
 
+pub use centroid::weighted_centroid;
 pub use field_of_movement::field_of_movement;
-pub use fov::{directional_fov, range_fov};
-pub use pathfinding::a_star;
+pub use fov::{
+    directional_fov, line_of_sight, range_fov, range_fov_with_cover, range_fov_with_directions,
+};
+pub use influence::influence_map;
+pub use pathfinding::{
+    a_star, a_star_to, a_star_to_any, a_star_weighted, a_star_with_cost, a_star_with_neighbors,
+    a_star_wrapped, dijkstra, reachable,
+};
+pub use region::{is_polyhex, polyhex_holes, region_is_contiguous, region_perimeter};
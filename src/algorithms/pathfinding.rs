@@ -1,5 +1,8 @@
-use crate::Hex;
-use std::collections::{BinaryHeap, HashMap};
+use crate::{hex::HexHashMap, Hex, HexBounds};
+use std::{
+    collections::{BinaryHeap, HashSet},
+    hash::BuildHasher,
+};
 
 struct Node {
     coord: Hex,
@@ -27,7 +30,7 @@ impl Ord for Node {
     }
 }
 
-fn reconstruct_path(came_from: &HashMap<Hex, Hex>, end: Hex) -> Vec<Hex> {
+fn reconstruct_path(came_from: &HexHashMap<Hex>, end: Hex) -> Vec<Hex> {
     let mut path: Vec<_> =
         std::iter::successors(Some(end), move |&current| came_from.get(&current).copied())
             .collect();
@@ -104,26 +107,224 @@ fn reconstruct_path(came_from: &HashMap<Hex, Hex>, end: Hex) -> Vec<Hex> {
 /// });
 /// ```
 pub fn a_star(start: Hex, end: Hex, cost: impl Fn(Hex, Hex) -> Option<u32>) -> Option<Vec<Hex>> {
-    let heuristic = |h: Hex| h.unsigned_distance_to(end);
+    a_star_with_cost(start, end, cost).map(|(path, _)| path)
+}
+
+/// Performs A star pathfinding between `start` and `end`, returning the path
+/// along with its total cost.
+///
+/// The returned cost is the sum of the `cost` values of every step along the
+/// path, as computed during the search (without any heuristic). The cost of
+/// `start` itself is not included.
+///
+/// See [`a_star`] for more details on the arguments.
+///
+/// # Example
+///
+/// - Check if a unit can reach a destination with its movement points
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::a_star_with_cost;
+///
+/// let movement_points = 8;
+/// let (path, cost) = a_star_with_cost(hex(0, 0), hex(4, 0), |_, _| Some(2)).unwrap();
+/// assert_eq!(path.len(), 5);
+/// assert_eq!(cost, 8);
+/// assert!(cost <= movement_points);
+/// ```
+pub fn a_star_with_cost(
+    start: Hex,
+    end: Hex,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+) -> Option<(Vec<Hex>, u32)> {
+    search(
+        start,
+        end,
+        Hex::all_neighbors,
+        |h| h.unsigned_distance_to(end),
+        cost,
+    )
+}
+
+/// Performs A star pathfinding between `start` and `end` with a custom
+/// adjacency.
+///
+/// The `neighbors` function returns the coordinates reachable from a given
+/// coordinate, allowing diagonal moves, wrapped edges or teleporters.
+/// [`a_star`] uses [`Hex::all_neighbors`].
+///
+/// See [`a_star`] for more details on the other arguments.
+///
+/// # Heuristic
+///
+/// The heuristic is the [`Hex::unsigned_distance_to`] distance to `end`. For
+/// the returned path to be optimal, each move should cost at least the hex
+/// distance it covers: a diagonal move covers a distance of 2.
+///
+/// # Example
+///
+/// - Allow diagonal moves
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::a_star_with_neighbors;
+///
+/// let path = a_star_with_neighbors(
+///     hex(0, 0),
+///     hex(4, 4),
+///     |h| {
+///         h.all_neighbors()
+///             .into_iter()
+///             .chain(h.all_diagonals())
+///             .collect()
+///     },
+///     // Diagonal moves cost more
+///     |a, b| Some(a.unsigned_distance_to(b)),
+/// )
+/// .unwrap();
+/// assert_eq!(path.first(), Some(&hex(0, 0)));
+/// assert_eq!(path.last(), Some(&hex(4, 4)));
+/// ```
+pub fn a_star_with_neighbors(
+    start: Hex,
+    end: Hex,
+    neighbors: impl Fn(Hex) -> Vec<Hex>,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+) -> Option<Vec<Hex>> {
+    search(start, end, neighbors, |h| h.unsigned_distance_to(end), cost).map(|(path, _)| path)
+}
+
+/// Performs A star pathfinding between `start` and `end` on a wrapping map
+/// defined by `bounds`.
+///
+/// Every neighbor is wrapped with [`HexBounds::wrap`] before evaluating its
+/// cost, and the heuristic uses [`HexBounds::wrapped_distance`], so the
+/// returned path may cross the wrap seam. Every coordinate of the path is
+/// inside the `bounds`.
+///
+/// See [`a_star`] for more details on the other arguments.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::{a_star, a_star_wrapped};
+///
+/// let bounds = HexBounds::from_radius(10);
+/// let cost = |_, b| bounds.is_in_bounds(b).then_some(1);
+/// let [start, end] = [hex(-10, 5), hex(10, -5)];
+/// let path = a_star(start, end, cost).unwrap();
+/// let wrapped_path = a_star_wrapped(start, end, &bounds, cost).unwrap();
+/// // The wrapped path crosses the seam
+/// assert_eq!(path.len(), 21);
+/// assert_eq!(wrapped_path.len(), 2);
+/// ```
+pub fn a_star_wrapped(
+    start: Hex,
+    end: Hex,
+    bounds: &HexBounds,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+) -> Option<Vec<Hex>> {
+    let [start, end] = [start, end].map(|h| bounds.wrap(h));
+    search(
+        start,
+        end,
+        |h| h.all_neighbors().map(|n| bounds.wrap(n)),
+        |h| bounds.wrapped_distance(h, end),
+        cost,
+    )
+    .map(|(path, _)| path)
+}
 
+/// Performs weighted A star pathfinding between `start` and `end`
+///
+/// The distance heuristic of [`a_star`] is multiplied by `weight`, favoring
+/// coordinates closer to `end`. Higher weights explore fewer coordinates and
+/// find a path faster, at the expense of its cost. A `weight` of `1.0` behaves
+/// exactly like [`a_star`], values below `1.0` are treated as `1.0`.
+///
+/// See [`a_star`] for more details on the other arguments.
+///
+/// # Optimality
+///
+/// The returned path is only guaranteed to be optimal with a `weight` of
+/// `1.0`. Otherwise its cost is at most `weight` times the optimal cost.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::{a_star, a_star_weighted};
+///
+/// let [start, end] = [hex(0, 0), hex(10, -3)];
+/// let cost = |_, b: Hex| (b.x != 5 || b.y > 3).then_some(1);
+/// let path = a_star_weighted(start, end, cost, 2.0).unwrap();
+/// assert_eq!(path.first(), Some(&start));
+/// assert_eq!(path.last(), Some(&end));
+/// // No weight is the regular A star
+/// assert_eq!(a_star_weighted(start, end, cost, 1.0), a_star(start, end, cost));
+/// ```
+#[allow(
+    clippy::cast_precision_loss,
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss
+)]
+pub fn a_star_weighted(
+    start: Hex,
+    end: Hex,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+    weight: f32,
+) -> Option<Vec<Hex>> {
+    let weight = weight.max(1.0);
+    search(
+        start,
+        end,
+        Hex::all_neighbors,
+        |h| (h.unsigned_distance_to(end) as f32 * weight) as u32,
+        cost,
+    )
+    .map(|(path, _)| path)
+}
+
+/// A star search with a custom adjacency, returning the path and its cost
+fn search<I: IntoIterator<Item = Hex>>(
+    start: Hex,
+    end: Hex,
+    neighbors: impl Fn(Hex) -> I,
+    heuristic: impl Fn(Hex) -> u32,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+) -> Option<(Vec<Hex>, u32)> {
     // We return early if the end is not included
     cost(end, end)?;
+    search_any(start, |h| h == end, neighbors, heuristic, cost)
+}
+
+/// A star search to the first coordinate satisfying `is_goal`, with a custom
+/// adjacency, returning the path and its cost
+fn search_any<I: IntoIterator<Item = Hex>>(
+    start: Hex,
+    is_goal: impl Fn(Hex) -> bool,
+    neighbors: impl Fn(Hex) -> I,
+    heuristic: impl Fn(Hex) -> u32,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+) -> Option<(Vec<Hex>, u32)> {
     let start_node = Node {
         coord: start,
         score: heuristic(start) + cost(start, start)?,
     };
     let mut open = BinaryHeap::new();
     open.push(start_node);
-    let mut costs = HashMap::new();
+    let mut costs = HexHashMap::default();
     costs.insert(start, 0);
-    let mut came_from = HashMap::new();
+    let mut came_from = HexHashMap::default();
 
     while let Some(node) = open.pop() {
-        if node.coord == end {
-            return Some(reconstruct_path(&came_from, end));
+        if is_goal(node.coord) {
+            return Some((reconstruct_path(&came_from, node.coord), costs[&node.coord]));
         }
         let current_cost = costs[&node.coord];
-        for neighbor in node.coord.all_neighbors() {
+        for neighbor in neighbors(node.coord) {
             let Some(cost) = cost(node.coord, neighbor) else {
                 continue;
             };
@@ -140,3 +341,228 @@ pub fn a_star(start: Hex, end: Hex, cost: impl Fn(Hex, Hex) -> Option<u32>) -> O
     }
     None
 }
+
+/// Performs pathfinding from `start` to the nearest coordinate satisfying
+/// `is_goal`
+///
+/// Unlike [`a_star`] there is no single destination to estimate the remaining
+/// distance, so this performs a *Dijkstra* search: the returned path is the
+/// cheapest path to any goal.
+///
+/// The `cost` parameter should give the cost of each coordinate (`Some`) or
+/// indicate the coordinate is not included in the pathfinding (`None`).
+///
+/// # Arguments
+///
+/// * `start` - start node
+/// * `is_goal` - goal predicate, returning `true` for any valid destination
+/// * `cost` - cost function taking a node pair (`a` -> `b`) and returning the
+///   logical cost to go from `a` to `b`
+///
+/// # Warning
+///
+/// The search stops only when a goal is found or every reachable coordinate
+/// was explored. If no goal may be reachable, `cost` must return `None`
+/// outside of your map boundaries or this function will never return.
+///
+/// # Example
+///
+/// - Find the path to the nearest water tile
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::collections::HashSet;
+/// use hexx::algorithms::a_star_to;
+///
+/// let bounds = HexBounds::from_radius(20);
+/// let water: HashSet<Hex> = [hex(5, 0), hex(-3, 1)].into();
+/// let path = a_star_to(
+///     hex(0, 0),
+///     |h| water.contains(&h),
+///     |_, b| bounds.is_in_bounds(b).then_some(1),
+/// )
+/// .unwrap();
+/// assert_eq!(path.last(), Some(&hex(-3, 1)));
+/// ```
+pub fn a_star_to(
+    start: Hex,
+    is_goal: impl Fn(Hex) -> bool,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+) -> Option<Vec<Hex>> {
+    search_any(start, is_goal, Hex::all_neighbors, |_| 0, cost).map(|(path, _)| path)
+}
+
+/// Performs A star pathfinding from `start` to the cheapest reachable
+/// coordinate of `goals`
+///
+/// The heuristic is the minimum [`Hex::unsigned_distance_to`] distance to any
+/// of the `goals`, and the search stops as soon as a goal is reached. The
+/// returned path ends at the goal with the cheapest path.
+///
+/// See [`a_star`] for more details on the other arguments, and [`a_star_to`]
+/// for goals defined by a predicate.
+///
+/// # Warning
+///
+/// If no goal is reachable, `cost` must return `None` outside of your map
+/// boundaries or this function will never return.
+///
+/// # Example
+///
+/// - Find the path to the nearest enemy
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::collections::HashSet;
+/// use hexx::algorithms::a_star_to_any;
+///
+/// let enemies: HashSet<Hex> = [hex(5, 0), hex(-3, 1), hex(0, 8)].into();
+/// // The western enemy is behind a wall
+/// let cost = |_, b: Hex| (b.x != -1).then_some(1);
+/// let path = a_star_to_any(hex(0, 0), &enemies, cost).unwrap();
+/// assert_eq!(path.len(), 6);
+/// assert_eq!(path.last(), Some(&hex(5, 0)));
+/// ```
+pub fn a_star_to_any<S: BuildHasher>(
+    start: Hex,
+    goals: &HashSet<Hex, S>,
+    cost: impl Fn(Hex, Hex) -> Option<u32>,
+) -> Option<Vec<Hex>> {
+    if goals.is_empty() {
+        return None;
+    }
+    search_any(
+        start,
+        |h| goals.contains(&h),
+        Hex::all_neighbors,
+        |h| {
+            goals
+                .iter()
+                .map(|g| h.unsigned_distance_to(*g))
+                .min()
+                .unwrap_or(0)
+        },
+        cost,
+    )
+    .map(|(path, _)| path)
+}
+
+/// Computes the minimal accumulated cost from `start` to every reachable
+/// coordinate, using *Dijkstra*'s algorithm.
+///
+/// The returned map contains `start` with a cost of 0, and every reachable
+/// coordinate with its minimal accumulated cost. This is useful for influence
+/// maps, threat ranges or AI targeting.
+///
+/// # Arguments
+///
+/// * `start` - start node
+/// * `cost` - cost function returning the cost of moving into a coordinate
+///   (`Some`), or `None` for impassable coordinates
+/// * `max_cost` - optional maximum accumulated cost. Coordinates costing more
+///   are not included and not expanded further.
+///
+/// # Warning
+///
+/// Without `max_cost`, `cost` must return `None` outside of your map
+/// boundaries or this function will never return.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::dijkstra;
+///
+/// let costs = dijkstra(hex(0, 0), |_| Some(2), Some(6));
+/// assert_eq!(costs[&hex(0, 0)], 0);
+/// assert_eq!(costs[&hex(3, 0)], 6);
+/// assert_eq!(costs.len(), Hex::range_count(3) as usize);
+/// ```
+pub fn dijkstra(
+    start: Hex,
+    cost: impl Fn(Hex) -> Option<u32>,
+    max_cost: Option<u32>,
+) -> HexHashMap<u32> {
+    let max_cost = max_cost.unwrap_or(u32::MAX);
+    let mut open = BinaryHeap::new();
+    open.push(Node {
+        coord: start,
+        score: 0,
+    });
+    let mut costs = HexHashMap::default();
+    costs.insert(start, 0);
+
+    while let Some(node) = open.pop() {
+        // Skip outdated nodes
+        if costs[&node.coord] < node.score {
+            continue;
+        }
+        for neighbor in node.coord.all_neighbors() {
+            let Some(cost) = cost(neighbor) else {
+                continue;
+            };
+            let neighbor_cost = node.score.saturating_add(cost);
+            if neighbor_cost > max_cost {
+                continue;
+            }
+            if costs.get(&neighbor).is_none_or(|c| *c > neighbor_cost) {
+                costs.insert(neighbor, neighbor_cost);
+                open.push(Node {
+                    coord: neighbor,
+                    score: neighbor_cost,
+                });
+            }
+        }
+    }
+    costs
+}
+
+/// Computes every coordinate reachable from `start` within a movement
+/// `budget`, using a bounded *Dijkstra* search.
+///
+/// The returned set contains `start` and every coordinate whose minimal
+/// accumulated cost is at most `budget`. This is the classic *movement range*
+/// of tactics games.
+///
+/// # Arguments
+///
+/// * `start` - start node
+/// * `budget` - maximum accumulated cost
+/// * `cost` - cost function returning the cost of moving into a coordinate
+///   (`Some`), or `None` for impassable coordinates
+///
+/// # Warning
+///
+/// Zero cost coordinates don't consume the `budget`, `cost` must return a
+/// positive value or `None` outside of your map boundaries or this function
+/// will never return. See [`field_of_movement`](super::field_of_movement)
+/// for a movement range where every step costs at least 1.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::algorithms::reachable;
+///
+/// // Forests cost 2, mountains are impassable
+/// let forests = [hex(1, 0), hex(1, -1)];
+/// let mountains = [hex(-1, 0), hex(-1, 1)];
+/// let tiles = reachable(hex(0, 0), 2, |h| {
+///     if mountains.contains(&h) {
+///         None
+///     } else if forests.contains(&h) {
+///         Some(2)
+///     } else {
+///         Some(1)
+///     }
+/// });
+/// assert!(tiles.contains(&hex(0, 0)));
+/// assert!(tiles.contains(&hex(1, 0)));
+/// assert!(!tiles.contains(&hex(2, 0)));
+/// assert!(!tiles.contains(&hex(-1, 0)));
+/// assert!(tiles.contains(&hex(0, 2)));
+/// ```
+#[must_use]
+pub fn reachable(start: Hex, budget: u32, cost: impl Fn(Hex) -> Option<u32>) -> HashSet<Hex> {
+    dijkstra(start, cost, Some(budget)).into_keys().collect()
+}
@@ -0,0 +1,134 @@
+use crate::Hex;
+use std::{collections::HashSet, hash::BuildHasher};
+
+/// Computes the perimeter of a region, as the number of coordinate edges not
+/// shared with another member of the region.
+///
+/// # Examples
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::{collections::HashSet, hash::BuildHasher};
+/// use hexx::algorithms::region_perimeter;
+///
+/// let single: HashSet<Hex> = [hex(0, 0)].into();
+/// assert_eq!(region_perimeter(&single), 6);
+/// let pair: HashSet<Hex> = [hex(0, 0), hex(1, 0)].into();
+/// assert_eq!(region_perimeter(&pair), 10);
+/// ```
+#[must_use]
+pub fn region_perimeter<S: BuildHasher>(set: &HashSet<Hex, S>) -> u32 {
+    set.iter()
+        .flat_map(|h| h.all_neighbors())
+        .map(|n| u32::from(!set.contains(&n)))
+        .sum()
+}
+
+/// Checks if every coordinate of the region can be reached from any other
+/// through neighboring members of the region.
+///
+/// An empty region is considered contiguous
+///
+/// # Examples
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::{collections::HashSet, hash::BuildHasher};
+/// use hexx::algorithms::region_is_contiguous;
+///
+/// let region: HashSet<Hex> = hex(0, 0).line_to(hex(5, -2)).collect();
+/// assert!(region_is_contiguous(&region));
+/// let region: HashSet<Hex> = [hex(0, 0), hex(2, 0)].into();
+/// assert!(!region_is_contiguous(&region));
+/// ```
+#[must_use]
+pub fn region_is_contiguous<S: BuildHasher>(set: &HashSet<Hex, S>) -> bool {
+    let Some(start) = set.iter().next() else {
+        return true;
+    };
+    let mut visited = HashSet::with_capacity(set.len());
+    visited.insert(*start);
+    let mut stack = vec![*start];
+    while let Some(coord) = stack.pop() {
+        for neighbor in coord.all_neighbors() {
+            if set.contains(&neighbor) && visited.insert(neighbor) {
+                stack.push(neighbor);
+            }
+        }
+    }
+    visited.len() == set.len()
+}
+
+/// Checks if the region is a valid *polyhex*: a non empty region of
+/// contiguous coordinates.
+///
+/// See [`region_is_contiguous`]
+///
+/// # Examples
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::collections::HashSet;
+/// use hexx::algorithms::is_polyhex;
+///
+/// let piece: HashSet<Hex> = [hex(0, 0), hex(1, 0), hex(1, 1)].into();
+/// assert!(is_polyhex(&piece));
+/// let split: HashSet<Hex> = [hex(0, 0), hex(2, 0)].into();
+/// assert!(!is_polyhex(&split));
+/// assert!(!is_polyhex(&HashSet::new()));
+/// ```
+#[must_use]
+pub fn is_polyhex<S: BuildHasher>(set: &HashSet<Hex, S>) -> bool {
+    !set.is_empty() && region_is_contiguous(set)
+}
+
+/// Counts the *holes* of a region: the groups of contiguous coordinates
+/// outside of the region which are fully enclosed by it.
+///
+/// # Examples
+///
+/// ```rust
+/// # use hexx::*;
+/// # use std::collections::HashSet;
+/// use hexx::algorithms::polyhex_holes;
+///
+/// let ring: HashSet<Hex> = hex(3, -1).ring(2).collect();
+/// assert_eq!(polyhex_holes(&ring), 1);
+/// let disk: HashSet<Hex> = hex(3, -1).range(2).collect();
+/// assert_eq!(polyhex_holes(&disk), 0);
+/// ```
+#[must_use]
+pub fn polyhex_holes<S: BuildHasher>(set: &HashSet<Hex, S>) -> usize {
+    let Some(center) = set.iter().next().copied() else {
+        return 0;
+    };
+    // Every coordinate on the ring around the region is outside of it
+    let radius = set
+        .iter()
+        .map(|h| h.unsigned_distance_to(center))
+        .max()
+        .unwrap_or_default()
+        + 1;
+    let mut visited: HashSet<Hex> = set.iter().copied().collect();
+    let fill = |start: Hex, visited: &mut HashSet<Hex>| {
+        visited.insert(start);
+        let mut stack = vec![start];
+        while let Some(coord) = stack.pop() {
+            for neighbor in coord.all_neighbors() {
+                if neighbor.unsigned_distance_to(center) <= radius && visited.insert(neighbor) {
+                    stack.push(neighbor);
+                }
+            }
+        }
+    };
+    // The outside of the region
+    fill(center + Hex::X * radius.cast_signed(), &mut visited);
+    let mut holes = 0;
+    for coord in center.range(radius) {
+        if !visited.contains(&coord) {
+            fill(coord, &mut visited);
+            holes += 1;
+        }
+    }
+    holes
+}
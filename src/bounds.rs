@@ -1,4 +1,4 @@
-use crate::Hex;
+use crate::{hex::ExactSizeHexIterator, Hex, HexOrientation, OffsetHexMode};
 
 /// Hexagonal bounds utils, represented as a center and radius.
 /// This type can be defined manually or from a [`Hex`] iterator.
@@ -81,11 +81,103 @@ impl HexBounds {
 
     #[inline]
     #[must_use]
+    #[doc(alias = "contains")]
     /// Checks if `rhs` is in bounds
     pub const fn is_in_bounds(&self, rhs: Hex) -> bool {
         self.center.unsigned_distance_to(rhs) <= self.radius
     }
 
+    /// Returns the inclusive `[min, max]` range of each cubic coordinate
+    /// (`x`, `y`, `z`) in bounds
+    #[allow(clippy::cast_possible_wrap)]
+    const fn cubic_ranges(&self) -> [[i32; 2]; 3] {
+        let [x, y, z] = self.center.to_cubic_array();
+        let r = self.radius as i32;
+        [[x - r, x + r], [y - r, y + r], [z - r, z + r]]
+    }
+
+    /// Computes the smallest bounds containing every coordinate whose cubic
+    /// coordinates lie in the given `ranges`.
+    ///
+    /// The `ranges` must be *tight*, meaning every bound is reached by at
+    /// least one coordinate.
+    #[allow(clippy::cast_sign_loss)]
+    fn enclosing(ranges: [[i32; 2]; 3]) -> Self {
+        let width = ranges.iter().map(|[min, max]| max - min).max().unwrap_or(0);
+        // Hexagonal bounds are the intersection of three axis slabs
+        let mut radius = (width + 1) / 2;
+        loop {
+            let centers = ranges.map(|[min, max]| [max - radius, min + radius]);
+            let low: i32 = centers.iter().map(|[min, _]| min).sum();
+            let high: i32 = centers.iter().map(|[_, max]| max).sum();
+            if low <= 0 && high >= 0 {
+                // We pick a center satisfying `x + y + z = 0`
+                let mut missing = -low;
+                let [x, y, _] = centers.map(|[min, max]| {
+                    let step = missing.min(max - min);
+                    missing -= step;
+                    min + step
+                });
+                return Self::new(Hex::new(x, y), radius as u32);
+            }
+            radius += 1;
+        }
+    }
+
+    /// Computes the smallest bounds containing both `self` and `rhs`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let a = HexBounds::new(Hex::ZERO, 2);
+    /// let b = HexBounds::new(Hex::new(6, 0), 2);
+    /// let union = a.union(b);
+    /// assert_eq!(union, HexBounds::new(Hex::new(3, 0), 5));
+    /// assert!(a.all_coords().chain(b.all_coords()).all(|h| union.is_in_bounds(h)));
+    /// ```
+    #[must_use]
+    pub fn union(&self, rhs: Self) -> Self {
+        let [a, b] = [self.cubic_ranges(), rhs.cubic_ranges()];
+        Self::enclosing([0, 1, 2].map(|i| [a[i][0].min(b[i][0]), a[i][1].max(b[i][1])]))
+    }
+
+    /// Computes the smallest bounds containing the intersection of `self`
+    /// and `rhs`, or `None` if they don't overlap.
+    ///
+    /// The intersection of two hexagonal bounds is not always hexagonal, in
+    /// which case the returned bounds may contain extra coordinates. It is
+    /// exact when one of the bounds contains the other. Use
+    /// [`Self::intersecting_with`] to iterate over the exact intersection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let a = HexBounds::new(Hex::ZERO, 5);
+    /// let b = HexBounds::new(Hex::new(1, 1), 2);
+    /// assert_eq!(a.intersection(b), Some(b));
+    /// let c = HexBounds::new(Hex::new(20, 0), 2);
+    /// assert_eq!(a.intersection(c), None);
+    /// ```
+    #[must_use]
+    pub fn intersection(&self, rhs: Self) -> Option<Self> {
+        let [a, b] = [self.cubic_ranges(), rhs.cubic_ranges()];
+        let ranges = [0, 1, 2].map(|i| [a[i][0].max(b[i][0]), a[i][1].min(b[i][1])]);
+        // Each cubic coordinate is constrained by the two others
+        let ranges = [0, 1, 2].map(|i| {
+            let [j, k] = [(i + 1) % 3, (i + 2) % 3];
+            [
+                ranges[i][0].max(-ranges[j][1] - ranges[k][1]),
+                ranges[i][1].min(-ranges[j][0] - ranges[k][0]),
+            ]
+        });
+        ranges
+            .iter()
+            .all(|[min, max]| min <= max)
+            .then(|| Self::enclosing(ranges))
+    }
+
     #[must_use]
     #[inline]
     #[doc(alias = "coords_count")]
@@ -139,6 +231,25 @@ impl HexBounds {
     pub fn wrap(&self, coord: Hex) -> Hex {
         self.wrap_local(coord) + self.center
     }
+
+    /// Computes the distance between `a` and `b` on a wrapping map, which is
+    /// the shortest distance between `a` and any wrapped position of `b`.
+    ///
+    /// > See also [`Self::wrap`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let bounds = HexBounds::from_radius(10);
+    /// let [a, b] = [hex(-10, 5), hex(10, -5)];
+    /// assert_eq!(a.unsigned_distance_to(b), 20);
+    /// assert_eq!(bounds.wrapped_distance(a, b), 1);
+    /// ```
+    #[must_use]
+    pub fn wrapped_distance(&self, a: Hex, b: Hex) -> u32 {
+        (b - a).wrap_in_range(self.radius).ulength()
+    }
 }
 
 impl FromIterator<Hex> for HexBounds {
@@ -156,9 +267,275 @@ impl FromIterator<Hex> for HexBounds {
     }
 }
 
+/// Rectangular bounds utils, represented in [offset] coordinates.
+///
+/// Contrary to [`HexBounds`] which defines a hexagonal area, this type defines
+/// a rectangular map of hexagons, with inclusive `min` and `max` offset
+/// coordinates given as `[COLUMN, ROW]`.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+///
+/// let bounds = HexRectBounds::from_size([20, 10], OffsetHexMode::Even, HexOrientation::Pointy);
+/// assert_eq!(bounds.hex_count(), 200);
+/// // Define a coordinate, even ouside of bounds
+/// let point = Hex::new(100, 100);
+/// assert!(!bounds.is_in_bounds(point));
+/// // Retrieve the wrapped position in the map
+/// let wrapped_point = bounds.wrap(point);
+/// assert!(bounds.is_in_bounds(wrapped_point));
+/// ```
+///
+/// # Wrapping
+///
+/// For seamless toroidal maps the amount of offsetted lines (rows for pointy
+/// orientation, columns for flat orientation) should be even, otherwise the
+/// offset parity breaks at the wrapping boundary.
+///
+/// [offset]: https://www.redblobgames.com/grids/hexagons/#coordinates-offset
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct HexRectBounds {
+    /// Minimum `[COLUMN, ROW]` offset coordinates, inclusive
+    pub min: [i32; 2],
+    /// Maximum `[COLUMN, ROW]` offset coordinates, inclusive
+    pub max: [i32; 2],
+    /// Offset coordinates mode
+    pub mode: OffsetHexMode,
+    /// Hexagon orientation of the offset coordinates
+    pub orientation: HexOrientation,
+}
+
+impl HexRectBounds {
+    /// Instantiates new bounds from inclusive `min` and `max` offset
+    /// coordinates
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        min: [i32; 2],
+        max: [i32; 2],
+        mode: OffsetHexMode,
+        orientation: HexOrientation,
+    ) -> Self {
+        Self {
+            min,
+            max,
+            mode,
+            orientation,
+        }
+    }
+
+    /// Instantiates new bounds of `[COLUMNS, ROWS]` size starting at the
+    /// `[0, 0]` offset coordinate
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `size` has a zero value
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn from_size(
+        [columns, rows]: [u32; 2],
+        mode: OffsetHexMode,
+        orientation: HexOrientation,
+    ) -> Self {
+        assert!(columns > 0 && rows > 0, "Bounds size must be positive");
+        Self::new(
+            [0, 0],
+            [columns as i32 - 1, rows as i32 - 1],
+            mode,
+            orientation,
+        )
+    }
+
+    /// Returns the `[COLUMNS, ROWS]` size of the bounds
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn size(&self) -> [u32; 2] {
+        [
+            (self.max[0] - self.min[0] + 1) as u32,
+            (self.max[1] - self.min[1] + 1) as u32,
+        ]
+    }
+
+    /// Converts `coord` to the bounds offset coordinates
+    #[inline]
+    const fn offset_of(&self, coord: Hex) -> [i32; 2] {
+        coord.to_offset_coordinates(self.mode, self.orientation)
+    }
+
+    /// Converts offset coordinates to a [`Hex`]
+    #[inline]
+    const fn coord_at(&self, offset: [i32; 2]) -> Hex {
+        Hex::from_offset_coordinates(offset, self.mode, self.orientation)
+    }
+
+    #[inline]
+    #[must_use]
+    #[doc(alias = "contains")]
+    /// Checks if `rhs` is in bounds
+    pub const fn is_in_bounds(&self, rhs: Hex) -> bool {
+        let [col, row] = self.offset_of(rhs);
+        col >= self.min[0] && col <= self.max[0] && row >= self.min[1] && row <= self.max[1]
+    }
+
+    #[must_use]
+    #[inline]
+    #[doc(alias = "coords_count")]
+    #[doc(alias = "len")]
+    /// Returns the number of hexagons in bounds
+    pub const fn hex_count(&self) -> usize {
+        let [columns, rows] = self.size();
+        columns as usize * rows as usize
+    }
+
+    #[doc(alias = "all_items")]
+    #[must_use]
+    /// Returns an iterator with all the coordinates in bounds, row by row
+    pub fn all_coords(&self) -> impl ExactSizeIterator<Item = Hex> {
+        let bounds = *self;
+        let iter = (self.min[1]..=self.max[1]).flat_map(move |row| {
+            (bounds.min[0]..=bounds.max[0]).map(move |col| bounds.coord_at([col, row]))
+        });
+        ExactSizeHexIterator {
+            iter,
+            count: self.hex_count(),
+        }
+    }
+
+    /// Wraps `coord`, returning a new coodinate inside of the bounds.
+    ///
+    /// > This allows for seamless *wraparound* rectangular maps, see the type
+    /// > documentation for the size requirements
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn wrap(&self, coord: Hex) -> Hex {
+        let [col, row] = self.offset_of(coord);
+        let [columns, rows] = self.size();
+        self.coord_at([
+            self.min[0] + (col - self.min[0]).rem_euclid(columns as i32),
+            self.min[1] + (row - self.min[1]).rem_euclid(rows as i32),
+        ])
+    }
+}
+
+/// Parallelogram bounds utils, represented in axial coordinates.
+///
+/// This type defines a parallelogram map of hexagons, with inclusive `min`
+/// and `max` axial coordinates. These bounds match the coordinates of
+/// [`shapes::parallelogram`](crate::shapes::parallelogram).
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+///
+/// let bounds = HexParallelogramBounds::new(hex(-5, 0), hex(4, 9));
+/// assert_eq!(bounds.hex_count(), 100);
+/// // Define a coordinate, even ouside of bounds
+/// let point = Hex::new(100, 100);
+/// assert!(!bounds.is_in_bounds(point));
+/// // Retrieve the wrapped position in the map
+/// let wrapped_point = bounds.wrap(point);
+/// assert!(bounds.is_in_bounds(wrapped_point));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct HexParallelogramBounds {
+    /// Minimum axial coordinates, inclusive
+    pub min: Hex,
+    /// Maximum axial coordinates, inclusive
+    pub max: Hex,
+}
+
+impl HexParallelogramBounds {
+    /// Instantiates new bounds from inclusive `min` and `max` axial
+    /// coordinates
+    #[inline]
+    #[must_use]
+    pub const fn new(min: Hex, max: Hex) -> Self {
+        Self { min, max }
+    }
+
+    /// Instantiates new bounds of `[X, Y]` size starting at [`Hex::ZERO`]
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `size` has a zero value
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn from_size([x, y]: [u32; 2]) -> Self {
+        assert!(x > 0 && y > 0, "Bounds size must be positive");
+        Self::new(Hex::ZERO, Hex::new(x as i32 - 1, y as i32 - 1))
+    }
+
+    /// Returns the `[X, Y]` size of the bounds
+    #[inline]
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    pub const fn size(&self) -> [u32; 2] {
+        [
+            (self.max.x - self.min.x + 1) as u32,
+            (self.max.y - self.min.y + 1) as u32,
+        ]
+    }
+
+    #[inline]
+    #[must_use]
+    #[doc(alias = "contains")]
+    /// Checks if `rhs` is in bounds
+    pub const fn is_in_bounds(&self, rhs: Hex) -> bool {
+        rhs.x >= self.min.x && rhs.x <= self.max.x && rhs.y >= self.min.y && rhs.y <= self.max.y
+    }
+
+    #[must_use]
+    #[inline]
+    #[doc(alias = "coords_count")]
+    #[doc(alias = "len")]
+    /// Returns the number of hexagons in bounds
+    pub const fn hex_count(&self) -> usize {
+        let [x, y] = self.size();
+        x as usize * y as usize
+    }
+
+    #[doc(alias = "all_items")]
+    #[must_use]
+    /// Returns an iterator with all the coordinates in bounds
+    pub fn all_coords(&self) -> impl ExactSizeIterator<Item = Hex> {
+        crate::shapes::parallelogram(self.min, self.max)
+    }
+
+    /// Wraps `coord`, returning a new coodinate inside of the bounds.
+    ///
+    /// > This allows for seamless *wraparound* parallelogram maps, without
+    /// > any size requirement
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub const fn wrap(&self, coord: Hex) -> Hex {
+        let [x, y] = self.size();
+        Hex::new(
+            self.min.x + (coord.x - self.min.x).rem_euclid(x as i32),
+            self.min.y + (coord.y - self.min.y).rem_euclid(y as i32),
+        )
+    }
+}
+
+impl From<crate::shapes::Parallelogram> for HexParallelogramBounds {
+    fn from(shape: crate::shapes::Parallelogram) -> Self {
+        Self::new(shape.min, shape.max)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn in_bounds_work() {
@@ -176,6 +553,55 @@ mod tests {
         assert_eq!(intersection.count(), 9);
     }
 
+    #[test]
+    fn intersection() {
+        let ba = HexBounds::new(Hex::new(-2, 1), 6);
+        for center in Hex::ZERO.range(12) {
+            for radius in 0..5 {
+                let bb = HexBounds::new(center, radius);
+                let expected: HashSet<_> = ba.intersecting_with(bb).collect();
+                let Some(intersection) = ba.intersection(bb) else {
+                    assert!(expected.is_empty());
+                    continue;
+                };
+                let coords: HashSet<_> = intersection.all_coords().collect();
+                assert!(coords.is_superset(&expected));
+                // Intersecting bounds are tight
+                assert!(expected.iter().any(|h| {
+                    h.unsigned_distance_to(intersection.center) == intersection.radius
+                }));
+                // Containment is exact
+                if expected.len() == bb.hex_count() {
+                    assert_eq!(intersection, bb);
+                    assert_eq!(coords, expected);
+                }
+            }
+        }
+        let bb = HexBounds::new(Hex::new(4, 0), 3);
+        let intersection = HexBounds::new(Hex::ZERO, 3).intersection(bb);
+        assert_eq!(intersection.map(|b| b.hex_count()), Some(19));
+    }
+
+    #[test]
+    fn union() {
+        let ba = HexBounds::new(Hex::new(3, -1), 4);
+        for center in Hex::ZERO.range(12) {
+            for radius in 0..5 {
+                let bb = HexBounds::new(center, radius);
+                let union = ba.union(bb);
+                assert_eq!(union, bb.union(ba));
+                assert!(ba.all_coords().all(|h| union.is_in_bounds(h)));
+                assert!(bb.all_coords().all(|h| union.is_in_bounds(h)));
+                // The union is no larger than the naive bounds
+                let naive = ba.center.unsigned_distance_to(bb.center) + ba.radius + bb.radius;
+                assert!(union.radius <= naive.div_ceil(2).max(ba.radius).max(bb.radius));
+            }
+        }
+        assert_eq!(ba.union(ba), ba);
+        let inner = HexBounds::new(Hex::new(4, -1), 1);
+        assert_eq!(ba.union(inner), ba);
+    }
+
     #[test]
     fn wrapping_works() {
         let map = HexBounds::from_radius(3);
@@ -197,6 +623,122 @@ mod tests {
         assert_eq!(map.wrap(Hex::new(4, 6)), Hex::new(0, 0));
     }
 
+    #[test]
+    fn wrapping_offset_center() {
+        let center = Hex::new(5, -3);
+        for radius in 1..6 {
+            let bounds = HexBounds::new(center, radius);
+            // Translation vectors between the bounds and its mirrors
+            let mirror = Hex::new(1, 1) * radius.cast_signed() + Hex::Y;
+            let mirrors: Vec<Hex> = (0..6).map(|i| mirror.rotate_cw(i)).collect();
+            for coord in center.range(radius) {
+                assert_eq!(bounds.wrap(coord), coord);
+                assert_eq!(bounds.wrap_local(coord), coord - center);
+                for mirror in &mirrors {
+                    assert_eq!(bounds.wrap(coord + *mirror), coord);
+                }
+            }
+            // Coordinates just outside of the bounds
+            for coord in center.ring(radius + 1) {
+                let wrapped = bounds.wrap(coord);
+                assert!(bounds.is_in_bounds(wrapped));
+                assert!(mirrors.contains(&(coord - wrapped)));
+            }
+        }
+        let bounds = HexBounds::new(center, 2);
+        assert_eq!(
+            bounds.wrap(center + Hex::new(3, 0)),
+            center + Hex::new(-2, 2)
+        );
+        assert_eq!(bounds.wrap(center + Hex::new(2, 3)), center);
+    }
+
+    #[test]
+    fn wrapped_distance() {
+        let center = Hex::new(5, -3);
+        for radius in 1..6 {
+            let bounds = HexBounds::new(center, radius);
+            let mirror = Hex::new(1, 1) * radius.cast_signed() + Hex::Y;
+            let mirrors: Vec<Hex> = std::iter::once(Hex::ZERO)
+                .chain((0..6).map(|i| mirror.rotate_cw(i)))
+                .collect();
+            for a in center.range(radius) {
+                for b in center.range(radius) {
+                    let expected = mirrors.iter().map(|m| a.unsigned_distance_to(b + *m)).min();
+                    assert_eq!(Some(bounds.wrapped_distance(a, b)), expected);
+                    assert_eq!(bounds.wrapped_distance(a, b), bounds.wrapped_distance(b, a));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rect_bounds() {
+        for orientation in [HexOrientation::Flat, HexOrientation::Pointy] {
+            for mode in [OffsetHexMode::Even, OffsetHexMode::Odd] {
+                let bounds = HexRectBounds::new([-3, 2], [5, 9], mode, orientation);
+                assert_eq!(bounds.size(), [9, 8]);
+                let coords: HashSet<Hex> = bounds.all_coords().collect();
+                assert_eq!(coords.len(), bounds.hex_count());
+                assert_eq!(bounds.all_coords().len(), bounds.hex_count());
+                for h in Hex::ZERO.range(20) {
+                    assert_eq!(bounds.is_in_bounds(h), coords.contains(&h));
+                    let wrapped = bounds.wrap(h);
+                    assert!(bounds.is_in_bounds(wrapped));
+                    if bounds.is_in_bounds(h) {
+                        assert_eq!(wrapped, h);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parallelogram_bounds() {
+        let bounds = HexParallelogramBounds::new(Hex::new(-3, 2), Hex::new(5, 9));
+        assert_eq!(bounds.size(), [9, 8]);
+        let coords: HashSet<Hex> = bounds.all_coords().collect();
+        assert_eq!(coords.len(), bounds.hex_count());
+        assert_eq!(bounds.all_coords().len(), bounds.hex_count());
+        for h in Hex::ZERO.range(20) {
+            assert_eq!(bounds.is_in_bounds(h), coords.contains(&h));
+            let wrapped = bounds.wrap(h);
+            assert!(bounds.is_in_bounds(wrapped));
+            if bounds.is_in_bounds(h) {
+                assert_eq!(wrapped, h);
+            }
+        }
+        // Neighbors across the wrapping boundaries are neighbors in the map
+        let bounds = HexParallelogramBounds::from_size([7, 5]);
+        for h in bounds.all_coords() {
+            for n in h.all_neighbors() {
+                let wrapped = bounds.wrap(n);
+                assert_eq!(wrapped.x.rem_euclid(7), n.x.rem_euclid(7));
+                assert_eq!(wrapped.y.rem_euclid(5), n.y.rem_euclid(5));
+            }
+        }
+    }
+
+    #[test]
+    fn rect_bounds_wrapping() {
+        let bounds = HexRectBounds::from_size([10, 6], OffsetHexMode::Odd, HexOrientation::Pointy);
+        let offset = |h: Hex| h.to_offset_coordinates(bounds.mode, bounds.orientation);
+        let from = |o: [i32; 2]| Hex::from_offset_coordinates(o, bounds.mode, bounds.orientation);
+        // Neighbors across the wrapping boundaries are neighbors in the map
+        for h in bounds.all_coords() {
+            for n in h.all_neighbors() {
+                let wrapped = bounds.wrap(n);
+                assert!(bounds.is_in_bounds(wrapped));
+                let [col, row] = offset(wrapped);
+                let [n_col, n_row] = offset(n);
+                assert_eq!(col.rem_euclid(10), n_col.rem_euclid(10));
+                assert_eq!(row.rem_euclid(6), n_row.rem_euclid(6));
+            }
+        }
+        assert_eq!(bounds.wrap(from([10, 0])), from([0, 0]));
+        assert_eq!(bounds.wrap(from([-1, 7])), from([9, 1]));
+    }
+
     #[test]
     fn positive_radius() {
         for radius in 0..100_u32 {
@@ -1,4 +1,4 @@
-use crate::{Hex, HexOrientation};
+use crate::{EdgeDirection, Hex, HexOrientation};
 
 /// Layout mode for [doubled] coordinates conversion. See
 /// [`Hex::to_doubled_coordinates`] and [`Hex::from_doubled_coordinates`].
@@ -17,9 +17,21 @@ pub enum DoubledHexMode {
 /// Layout mode for [offset] coordinates conversion. See
 /// [`Hex::to_offset_coordinates`] and [`Hex::from_offset_coordinates`].
 ///
+/// Combined with the [`HexOrientation`], it covers the four common offset
+/// conventions, used by tilemap formats and editors like [Tiled]:
+///
+/// | Convention | Mode                    | Orientation                |
+/// |------------|-------------------------|----------------------------|
+/// | *odd-r*    | [`OffsetHexMode::Odd`]  | [`HexOrientation::Pointy`] |
+/// | *even-r*   | [`OffsetHexMode::Even`] | [`HexOrientation::Pointy`] |
+/// | *odd-q*    | [`OffsetHexMode::Odd`]  | [`HexOrientation::Flat`]   |
+/// | *even-q*   | [`OffsetHexMode::Even`] | [`HexOrientation::Flat`]   |
+///
+/// [Tiled]: https://www.mapeditor.org/
 /// [offset]: https://www.redblobgames.com/grids/hexagons/#coordinates-offset
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub enum OffsetHexMode {
     /// Depending on the orientation:
     ///
@@ -36,7 +48,24 @@ pub enum OffsetHexMode {
 impl Hex {
     /// Converts `self` to [doubled] coordinates according to the given `mode`.
     ///
-    /// The coordinates are returned as `[COLUMN, ROW]`
+    /// The coordinates are returned as `[COLUMN, ROW]`. The conversion is
+    /// lossless, [`Self::from_doubled_coordinates`] gives back `self`.
+    ///
+    /// Doubled coordinates avoid the parity cases of offset coordinates: the
+    /// neighbor steps are the same for every coordinate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let mode = DoubledHexMode::DoubledWidth;
+    /// let coord = hex(2, -1);
+    /// let [col, row] = coord.to_doubled_coordinates(mode);
+    /// assert_eq!([col, row], [3, -1]);
+    /// // Right neighbor, two columns away
+    /// let right = Hex::from_doubled_coordinates([col + 2, row], mode);
+    /// assert_eq!(right, coord.neighbor(EdgeDirection::POINTY_RIGHT));
+    /// ```
     ///
     /// [doubled]: https://www.redblobgames.com/grids/hexagons/#coordinates-doubled
     #[inline]
@@ -49,9 +78,25 @@ impl Hex {
     }
 
     /// Converts `self` to [offset] coordinates according to the given `mode`
-    /// and `orientation`
+    /// and `orientation`, see [`OffsetHexMode`] for the matching conventions.
+    ///
+    /// The coordinates are returned as `[COLUMN, ROW]`. The conversion is
+    /// lossless, [`Self::from_offset_coordinates`] gives back `self`.
+    ///
+    /// # Example
     ///
-    /// The coordinates are returned as `[COLUMN, ROW]`
+    /// ```rust
+    /// # use hexx::*;
+    /// // *odd-r* coordinates
+    /// let (mode, orientation) = (OffsetHexMode::Odd, HexOrientation::Pointy);
+    /// let coord = hex(-2, 3);
+    /// let [col, row] = coord.to_offset_coordinates(mode, orientation);
+    /// assert_eq!([col, row], [-1, 3]);
+    /// assert_eq!(
+    ///     Hex::from_offset_coordinates([col, row], mode, orientation),
+    ///     coord
+    /// );
+    /// ```
     ///
     /// [offset]: https://www.redblobgames.com/grids/hexagons/#coordinates-offset
     #[inline]
@@ -155,6 +200,90 @@ impl Hex {
     }
 }
 
+/// [Red Blob Games] conventions interoperability.
+///
+/// Red Blob Games *axial* `(q, r)` and *cube* `(q, r, s)` coordinates match
+/// the [`Hex`] `x`, `y` and `z` coordinates, but its pixel space has a
+/// **downward** `Y` axis, while [`HexLayout`] uses an upward `Y` axis by
+/// default. To reproduce its maps without mirroring them use
+/// [`HexLayout::invert_y`].
+///
+/// Red Blob Games neighbor directions are also listed in the opposite order,
+/// see [`EdgeDirection::from_rbg_index`].
+///
+/// [Red Blob Games]: https://www.redblobgames.com/grids/hexagons/
+/// [`HexLayout`]: crate::HexLayout
+/// [`HexLayout::invert_y`]: crate::HexLayout::invert_y
+impl Hex {
+    /// Converts [Red Blob Games] *cube* coordinates `[q, r, s]` to [`Hex`]
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug mode if `q + r + s != 0`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let coord = Hex::from_rbg_cube([2, -3, 1]);
+    /// assert_eq!(coord, hex(2, -3));
+    /// assert_eq!(coord.to_rbg_cube(), [2, -3, 1]);
+    /// ```
+    ///
+    /// [Red Blob Games]: https://www.redblobgames.com/grids/hexagons/#coordinates-cube
+    #[inline]
+    #[must_use]
+    pub const fn from_rbg_cube([q, r, s]: [i32; 3]) -> Self {
+        debug_assert!(q + r + s == 0);
+        Self::new(q, r)
+    }
+
+    /// Converts `self` to [Red Blob Games] *cube* coordinates `[q, r, s]`
+    ///
+    /// [Red Blob Games]: https://www.redblobgames.com/grids/hexagons/#coordinates-cube
+    #[inline]
+    #[must_use]
+    pub const fn to_rbg_cube(self) -> [i32; 3] {
+        [self.x, self.y, self.z()]
+    }
+}
+
+impl EdgeDirection {
+    /// Retrieves the direction matching the [Red Blob Games] neighbor
+    /// direction `index` in `0..6`.
+    ///
+    /// Red Blob Games directions start at `(+1, 0)` and go in the opposite
+    /// order of [`EdgeDirection::ALL_DIRECTIONS`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// // Red Blob Games direction `1` is `(+1, -1)`
+    /// let dir = EdgeDirection::from_rbg_index(1);
+    /// assert_eq!(dir.into_hex(), hex(1, -1));
+    /// assert_eq!(dir.to_rbg_index(), 1);
+    /// ```
+    ///
+    /// [Red Blob Games]: https://www.redblobgames.com/grids/hexagons/#neighbors-axial
+    #[inline]
+    #[must_use]
+    pub const fn from_rbg_index(index: u8) -> Self {
+        Self((6 - index % 6) % 6)
+    }
+
+    /// Retrieves the [Red Blob Games] neighbor direction index of `self`.
+    ///
+    /// See [`Self::from_rbg_index`]
+    ///
+    /// [Red Blob Games]: https://www.redblobgames.com/grids/hexagons/#neighbors-axial
+    #[inline]
+    #[must_use]
+    pub const fn to_rbg_index(self) -> u8 {
+        (6 - self.index()) % 6
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +299,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn doubled_neighbors() {
+        let steps = [
+            (
+                DoubledHexMode::DoubledWidth,
+                [[2, 0], [1, 1], [-1, 1], [-2, 0], [-1, -1], [1, -1]],
+            ),
+            (
+                DoubledHexMode::DoubledHeight,
+                [[1, 1], [0, 2], [-1, 1], [-1, -1], [0, -2], [1, -1]],
+            ),
+        ];
+        for hex in Hex::new(3, -8).range(10) {
+            for (mode, steps) in steps {
+                let [col, row] = hex.to_doubled_coordinates(mode);
+                let neighbors =
+                    steps.map(|[c, r]| Hex::from_doubled_coordinates([col + c, row + r], mode));
+                assert_eq!(neighbors, hex.all_neighbors());
+            }
+        }
+    }
+
     #[test]
     fn offset_coordinates() {
         for hex in Hex::ZERO.range(20) {
@@ -183,6 +334,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn offset_conventions() {
+        // Red Blob Games *odd-q*, *even-q*, *odd-r* and *even-r* values
+        let coord = Hex::new(1, -1);
+        let expected = [
+            (OffsetHexMode::Odd, HexOrientation::Flat, [1, -1]),
+            (OffsetHexMode::Even, HexOrientation::Flat, [1, 0]),
+            (OffsetHexMode::Odd, HexOrientation::Pointy, [0, -1]),
+            (OffsetHexMode::Even, HexOrientation::Pointy, [1, -1]),
+        ];
+        for (mode, orientation, offset) in expected {
+            assert_eq!(coord.to_offset_coordinates(mode, orientation), offset);
+            assert_eq!(
+                Hex::from_offset_coordinates(offset, mode, orientation),
+                coord
+            );
+        }
+    }
+
     #[test]
     fn hexmod_coordinates() {
         let range = 20;
@@ -192,4 +362,41 @@ mod tests {
             assert_eq!(converted, hex);
         }
     }
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn rbg_conversion() {
+        use crate::HexLayout;
+        use glam::Vec2;
+
+        // Red Blob Games axial direction vectors
+        const RBG_DIRECTIONS: [[i32; 2]; 6] = [[1, 0], [1, -1], [0, -1], [-1, 0], [-1, 1], [0, 1]];
+        for (i, [q, r]) in (0..).zip(RBG_DIRECTIONS) {
+            let dir = EdgeDirection::from_rbg_index(i);
+            assert_eq!(dir.into_hex(), Hex::from_rbg_cube([q, r, -q - r]));
+            assert_eq!(dir.to_rbg_index(), i);
+        }
+        let sqrt_3 = 3.0_f32.sqrt();
+        for orientation in [HexOrientation::Flat, HexOrientation::Pointy] {
+            let mut layout = HexLayout::new(orientation);
+            layout.invert_y();
+            for coord in Hex::ZERO.range(5) {
+                let [q, r, s] = coord.to_rbg_cube();
+                assert_eq!(Hex::from_rbg_cube([q, r, s]), coord);
+                assert_eq!(q + r + s, 0);
+                let [q, r] = [q as f32, r as f32];
+                // Red Blob Games pixel coordinates, `Y` pointing down
+                let pixel = match orientation {
+                    HexOrientation::Flat => {
+                        Vec2::new(1.5 * q, (sqrt_3 / 2.0).mul_add(q, sqrt_3 * r))
+                    }
+                    HexOrientation::Pointy => {
+                        Vec2::new(sqrt_3.mul_add(q, sqrt_3 / 2.0 * r), 1.5 * r)
+                    }
+                };
+                let world = layout.hex_to_world_pos(coord);
+                assert!(world.abs_diff_eq(Vec2::new(pixel.x, -pixel.y), 0.0001));
+            }
+        }
+    }
 }
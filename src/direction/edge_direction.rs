@@ -33,9 +33,11 @@ use std::{f32::consts::TAU, fmt::Debug};
 ///  - rotated *clockwise* with:
 ///     - [`Self::clockwise`] and [`Self::rotate_cw`]
 ///     - The shift right `>>` operator
+///     - The plus `+` operator with an `i32` step count
 ///  - rotated *counter clockwise* with:
 ///     - [`Self::counter_clockwise`] and [`Self::rotate_ccw`]
 ///     - The shift left `<<` operator
+///     - The minus `-` operator with an `i32` step count
 ///  - negated using the minus `-` operator
 ///  - multiplied by an `i32`, returning a [`Hex`](crate::Hex) vector
 ///
@@ -46,6 +48,8 @@ use std::{f32::consts::TAU, fmt::Debug};
 /// assert_eq!(-direction, EdgeDirection::FLAT_BOTTOM);
 /// assert_eq!(direction >> 1, EdgeDirection::FLAT_TOP_RIGHT);
 /// assert_eq!(direction << 1, EdgeDirection::FLAT_TOP_LEFT);
+/// assert_eq!(direction + 1, EdgeDirection::FLAT_TOP_RIGHT);
+/// assert_eq!(direction - 7, EdgeDirection::FLAT_TOP_LEFT);
 /// ```
 ///
 /// ## Storage
@@ -226,6 +230,37 @@ impl EdgeDirection {
         Hex::NEIGHBORS_COORDS[self.0 as usize]
     }
 
+    /// Retrieves the direction matching the given neighbor coordinate `step`.
+    /// Will return `None` if `step` is not one of the
+    /// [`Hex::NEIGHBORS_COORDS`](crate::Hex::NEIGHBORS_COORDS).
+    ///
+    /// This is the reverse operation of [`Self::into_hex`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let step = hex(0, -1);
+    /// assert_eq!(
+    ///     EdgeDirection::from_hex_step(step),
+    ///     Some(EdgeDirection::FLAT_TOP)
+    /// );
+    /// assert_eq!(EdgeDirection::from_hex_step(hex(2, 0)), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_hex_step(step: Hex) -> Option<Self> {
+        match step {
+            Hex { x: 1, y: 0 } => Some(Self(0)),
+            Hex { x: 0, y: 1 } => Some(Self(1)),
+            Hex { x: -1, y: 1 } => Some(Self(2)),
+            Hex { x: -1, y: 0 } => Some(Self(3)),
+            Hex { x: 0, y: -1 } => Some(Self(4)),
+            Hex { x: 1, y: -1 } => Some(Self(5)),
+            _ => None,
+        }
+    }
+
     /// Computes the opposite direction of `self`
     ///
     /// # Example
@@ -243,6 +278,32 @@ impl EdgeDirection {
         Self((self.0 + 3) % 6)
     }
 
+    /// Returns all directions except the opposite of `self`, in clockwise
+    /// order starting with `self`.
+    ///
+    /// This is useful for movement constraints like units which can't reverse
+    /// direction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let options = EdgeDirection::FLAT_TOP.non_reverse_options();
+    /// assert_eq!(options[0], EdgeDirection::FLAT_TOP);
+    /// assert!(!options.contains(&EdgeDirection::FLAT_BOTTOM));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn non_reverse_options(self) -> [Self; 5] {
+        [
+            self,
+            self.rotate_cw(1),
+            self.rotate_cw(2),
+            self.rotate_cw(4),
+            self.rotate_cw(5),
+        ]
+    }
+
     /// Returns the next direction in clockwise order
     ///
     /// # Example
@@ -1,4 +1,4 @@
-use std::ops::{Mul, Neg, Shl, Shr};
+use std::ops::{Add, AddAssign, Mul, Neg, Shl, Shr, Sub, SubAssign};
 
 use crate::{EdgeDirection, Hex, VertexDirection};
 
@@ -65,3 +65,63 @@ impl Mul<i32> for VertexDirection {
         Hex::from(self).mul(rhs)
     }
 }
+
+impl Add<i32> for EdgeDirection {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn add(self, rhs: i32) -> Self::Output {
+        self.rotate_cw(rhs.rem_euclid(6) as u8)
+    }
+}
+
+impl AddAssign<i32> for EdgeDirection {
+    fn add_assign(&mut self, rhs: i32) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<i32> for EdgeDirection {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.rotate_ccw(rhs.rem_euclid(6) as u8)
+    }
+}
+
+impl SubAssign<i32> for EdgeDirection {
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = *self - rhs;
+    }
+}
+
+impl Add<i32> for VertexDirection {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn add(self, rhs: i32) -> Self::Output {
+        self.rotate_cw(rhs.rem_euclid(6) as u8)
+    }
+}
+
+impl AddAssign<i32> for VertexDirection {
+    fn add_assign(&mut self, rhs: i32) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<i32> for VertexDirection {
+    type Output = Self;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn sub(self, rhs: i32) -> Self::Output {
+        self.rotate_ccw(rhs.rem_euclid(6) as u8)
+    }
+}
+
+impl SubAssign<i32> for VertexDirection {
+    fn sub_assign(&mut self, rhs: i32) {
+        *self = *self - rhs;
+    }
+}
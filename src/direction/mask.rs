@@ -0,0 +1,123 @@
+use super::EdgeDirection;
+use std::ops::{BitAnd, BitOr, BitOrAssign};
+
+/// Set of [`EdgeDirection`], stored as a 6 bit mask
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let mut mask = DirectionMask::EMPTY;
+/// mask.insert(EdgeDirection::FLAT_TOP);
+/// mask |= EdgeDirection::FLAT_BOTTOM;
+/// assert!(mask.contains(EdgeDirection::FLAT_TOP));
+/// assert!(!mask.contains(EdgeDirection::FLAT_TOP_LEFT));
+/// assert_eq!(mask.len(), 2);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[repr(transparent)]
+pub struct DirectionMask(u8);
+
+impl DirectionMask {
+    /// Mask with no direction
+    pub const EMPTY: Self = Self(0);
+    /// Mask with all 6 directions
+    pub const ALL: Self = Self(0b11_1111);
+
+    #[inline]
+    #[must_use]
+    /// Returns the mask containing only `direction`
+    pub const fn from_direction(direction: EdgeDirection) -> Self {
+        Self(1 << direction.index())
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the raw bits of the mask, where bit `i` matches the direction
+    /// of index `i`
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if `direction` is in the mask
+    pub const fn contains(self, direction: EdgeDirection) -> bool {
+        self.0 & Self::from_direction(direction).0 != 0
+    }
+
+    #[inline]
+    /// Adds `direction` to the mask
+    pub const fn insert(&mut self, direction: EdgeDirection) {
+        self.0 |= Self::from_direction(direction).0;
+    }
+
+    #[inline]
+    /// Removes `direction` from the mask
+    pub const fn remove(&mut self, direction: EdgeDirection) {
+        self.0 &= !Self::from_direction(direction).0;
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns `true` if the mask contains no direction
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    #[must_use]
+    /// Returns the number of directions in the mask
+    pub const fn len(self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Iterates through the directions in the mask, in
+    /// [`EdgeDirection::ALL_DIRECTIONS`] order
+    pub fn iter(self) -> impl Iterator<Item = EdgeDirection> {
+        EdgeDirection::iter().filter(move |dir| self.contains(*dir))
+    }
+}
+
+impl std::fmt::Debug for DirectionMask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<EdgeDirection> for DirectionMask {
+    fn from(direction: EdgeDirection) -> Self {
+        Self::from_direction(direction)
+    }
+}
+
+impl FromIterator<EdgeDirection> for DirectionMask {
+    fn from_iter<T: IntoIterator<Item = EdgeDirection>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::EMPTY, |mask, dir| mask | dir)
+    }
+}
+
+impl<T: Into<Self>> BitOr<T> for DirectionMask {
+    type Output = Self;
+
+    fn bitor(self, rhs: T) -> Self::Output {
+        Self(self.0 | rhs.into().0)
+    }
+}
+
+impl<T: Into<Self>> BitOrAssign<T> for DirectionMask {
+    fn bitor_assign(&mut self, rhs: T) {
+        *self = *self | rhs;
+    }
+}
+
+impl<T: Into<Self>> BitAnd<T> for DirectionMask {
+    type Output = Self;
+
+    fn bitand(self, rhs: T) -> Self::Output {
+        Self(self.0 & rhs.into().0)
+    }
+}
@@ -2,6 +2,8 @@
 mod edge_direction;
 /// Trait implementations
 mod impls;
+/// Edge direction set
+mod mask;
 /// Test module
 #[cfg(test)]
 mod tests;
@@ -11,6 +13,7 @@ mod vertex_direction;
 pub(crate) mod way;
 
 pub use edge_direction::EdgeDirection;
+pub use mask::DirectionMask;
 pub use vertex_direction::VertexDirection;
 pub use way::DirectionWay;
 
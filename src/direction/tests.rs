@@ -14,6 +14,20 @@ use std::f32::consts::PI;
 mod edge_directions {
     use super::*;
 
+    #[test]
+    fn non_reverse_options() {
+        for direction in EdgeDirection::ALL_DIRECTIONS {
+            let options = direction.non_reverse_options();
+            assert!(!options.contains(&-direction));
+            for other in EdgeDirection::ALL_DIRECTIONS {
+                if other != -direction {
+                    assert!(options.contains(&other));
+                }
+            }
+            assert_eq!(options[0], direction);
+        }
+    }
+
     #[test]
     fn rotate_ccw_cw() {
         for direction in EdgeDirection::ALL_DIRECTIONS {
@@ -201,6 +215,23 @@ mod edge_directions {
         }
     }
 
+    #[test]
+    fn hex_steps() {
+        let neighbors = Hex::ZERO.all_neighbors();
+        for (i, direction) in EdgeDirection::ALL_DIRECTIONS.into_iter().enumerate() {
+            assert_eq!(direction.into_hex(), neighbors[i]);
+            assert_eq!(direction.into_hex(), Hex::NEIGHBORS_COORDS[i]);
+            assert_eq!(EdgeDirection::from_hex_step(neighbors[i]), Some(direction));
+        }
+        for coord in Hex::ZERO.range(3) {
+            if coord.ulength() != 1 {
+                assert_eq!(EdgeDirection::from_hex_step(coord), None);
+                let origin = Hex::new(3, -2);
+                assert_eq!(origin.neighbor_direction(origin + coord), None);
+            }
+        }
+    }
+
     #[test]
     fn unit_vector() {
         let coord = Hex::ZERO;
@@ -224,6 +255,97 @@ mod vertex_direction {
 
     use super::*;
 
+    #[test]
+    fn non_reverse_options() {
+        for direction in VertexDirection::ALL_DIRECTIONS {
+            let options = direction.non_reverse_options();
+            assert!(!options.contains(&-direction));
+            for other in VertexDirection::ALL_DIRECTIONS {
+                if other != -direction {
+                    assert!(options.contains(&other));
+                }
+            }
+            assert_eq!(options[0], direction);
+        }
+    }
+
+    #[test]
+    fn hex_steps() {
+        let diagonals = Hex::ZERO.all_diagonals();
+        for (i, direction) in VertexDirection::ALL_DIRECTIONS.into_iter().enumerate() {
+            assert_eq!(direction.into_hex(), diagonals[i]);
+            assert_eq!(
+                VertexDirection::from_hex_step(diagonals[i]),
+                Some(direction)
+            );
+            let origin = Hex::new(3, -2);
+            let neighbor = origin.diagonal_neighbor(direction);
+            assert_eq!(
+                origin.diagonal_neighbor_direction(neighbor),
+                Some(direction)
+            );
+        }
+        for coord in Hex::ZERO.range(3) {
+            if !diagonals.contains(&coord) {
+                assert_eq!(VertexDirection::from_hex_step(coord), None);
+                let origin = Hex::new(3, -2);
+                assert_eq!(origin.diagonal_neighbor_direction(origin + coord), None);
+            }
+        }
+    }
+
+    #[test]
+    fn rotate_ccw_cw() {
+        for direction in VertexDirection::ALL_DIRECTIONS {
+            assert_eq!(direction, direction.rotate_cw(6));
+            assert_eq!(direction, direction.rotate_cw(12));
+            assert_eq!(direction, direction.rotate_cw(1).rotate_ccw(1));
+            assert_eq!(direction, direction.rotate_ccw(1).rotate_cw(1));
+            assert_eq!(direction.counter_clockwise(), direction.rotate_ccw(1));
+            assert_eq!(
+                direction.counter_clockwise().counter_clockwise(),
+                direction.rotate_ccw(2)
+            );
+            assert_eq!(direction.clockwise(), direction.rotate_cw(1));
+            assert_eq!(direction.clockwise().clockwise(), direction.rotate_cw(2));
+        }
+    }
+
+    #[test]
+    fn rotations_reverse_each_other() {
+        for direction in VertexDirection::ALL_DIRECTIONS {
+            assert_eq!(direction, direction.counter_clockwise().clockwise());
+            assert_eq!(direction, direction.clockwise().counter_clockwise());
+        }
+    }
+
+    #[test]
+    fn six_rotations_comes_home() {
+        for direction in VertexDirection::ALL_DIRECTIONS {
+            let mut clockwise_dir = direction;
+            let mut counter_clockwise_dir = direction;
+
+            for _ in 0..6 {
+                clockwise_dir = clockwise_dir.counter_clockwise();
+                counter_clockwise_dir = counter_clockwise_dir.clockwise();
+            }
+
+            assert_eq!(direction, clockwise_dir);
+            assert_eq!(direction, counter_clockwise_dir);
+        }
+    }
+
+    #[test]
+    fn opposite() {
+        assert!(VertexDirection::iter().eq(VertexDirection::ALL_DIRECTIONS));
+        for direction in VertexDirection::ALL_DIRECTIONS {
+            assert_eq!(-direction, direction.rotate_cw(3));
+            assert_eq!(direction.const_neg(), -direction);
+            assert_eq!(-(-direction), direction);
+            assert_eq!((-direction).into_hex(), -direction.into_hex());
+        }
+    }
+
     #[test]
     fn neigbhoring() {
         let coord = Hex::ZERO;
@@ -426,3 +548,63 @@ fn rad_deg() {
         }
     }
 }
+
+#[test]
+fn step_arithmetic() {
+    for steps in -13_i32..13 {
+        let [cw, ccw] = [steps.rem_euclid(6) as u8, (-steps).rem_euclid(6) as u8];
+        for direction in EdgeDirection::ALL_DIRECTIONS {
+            assert_eq!(direction + steps, direction.rotate_cw(cw));
+            assert_eq!(direction - steps, direction.rotate_ccw(cw));
+            assert_eq!(direction + steps, direction.rotate_ccw(ccw));
+            let mut dir = direction;
+            dir += steps;
+            dir -= steps;
+            assert_eq!(dir, direction);
+        }
+        for direction in VertexDirection::ALL_DIRECTIONS {
+            assert_eq!(direction + steps, direction.rotate_cw(cw));
+            assert_eq!(direction - steps, direction.rotate_ccw(cw));
+            assert_eq!(direction + steps, direction.rotate_ccw(ccw));
+            let mut dir = direction;
+            dir += steps;
+            dir -= steps;
+            assert_eq!(dir, direction);
+        }
+    }
+}
+
+mod direction_mask {
+    use super::*;
+
+    #[test]
+    fn insert_remove() {
+        let mut mask = DirectionMask::EMPTY;
+        assert!(mask.is_empty());
+        for (i, dir) in EdgeDirection::iter().enumerate() {
+            assert!(!mask.contains(dir));
+            mask.insert(dir);
+            assert!(mask.contains(dir));
+            assert_eq!(mask.len() as usize, i + 1);
+        }
+        assert_eq!(mask, DirectionMask::ALL);
+        for dir in EdgeDirection::iter() {
+            mask.remove(dir);
+            assert!(!mask.contains(dir));
+        }
+        assert!(mask.is_empty());
+    }
+
+    #[test]
+    fn iteration() {
+        let dirs = [
+            EdgeDirection::ALL_DIRECTIONS[1],
+            EdgeDirection::ALL_DIRECTIONS[4],
+        ];
+        let mask: DirectionMask = dirs.into_iter().collect();
+        assert_eq!(mask.bits(), 0b01_0010);
+        assert_eq!(mask.iter().collect::<Vec<_>>(), dirs);
+        assert_eq!(mask & dirs[0], DirectionMask::from_direction(dirs[0]));
+        assert_eq!(DirectionMask::ALL.iter().count(), 6);
+    }
+}
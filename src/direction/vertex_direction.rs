@@ -32,9 +32,11 @@ use std::{f32::consts::TAU, fmt::Debug};
 ///  - rotated *clockwise* with:
 ///     - [`Self::clockwise`] and [`Self::rotate_cw`]
 ///     - The shift right `>>` operator
+///     - The plus `+` operator with an `i32` step count
 ///  - rotated *counter clockwise* with:
 ///     - [`Self::counter_clockwise`] and [`Self::rotate_ccw`]
 ///     - The shift left `<<` operator
+///     - The minus `-` operator with an `i32` step count
 ///  - negated using the minus `-` operator
 ///  - multiplied by an `i32`, returning a [`Hex`](crate::Hex) vector
 ///
@@ -45,6 +47,8 @@ use std::{f32::consts::TAU, fmt::Debug};
 /// assert_eq!(-direction, VertexDirection::FLAT_LEFT);
 /// assert_eq!(direction >> 1, VertexDirection::FLAT_BOTTOM_RIGHT);
 /// assert_eq!(direction << 1, VertexDirection::FLAT_TOP_RIGHT);
+/// assert_eq!(direction + 1, VertexDirection::FLAT_BOTTOM_RIGHT);
+/// assert_eq!(direction - 7, VertexDirection::FLAT_TOP_RIGHT);
 /// ```
 ///
 /// ## Storage
@@ -239,6 +243,37 @@ impl VertexDirection {
         Hex::DIAGONAL_COORDS[self.0 as usize]
     }
 
+    /// Retrieves the direction matching the given diagonal coordinate `step`.
+    /// Will return `None` if `step` is not one of the
+    /// [`Hex::DIAGONAL_COORDS`](crate::Hex::DIAGONAL_COORDS).
+    ///
+    /// This is the reverse operation of [`Self::into_hex`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let step = hex(2, -1);
+    /// assert_eq!(
+    ///     VertexDirection::from_hex_step(step),
+    ///     Some(VertexDirection::FLAT_RIGHT)
+    /// );
+    /// assert_eq!(VertexDirection::from_hex_step(hex(1, 0)), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_hex_step(step: Hex) -> Option<Self> {
+        match step {
+            Hex { x: 2, y: -1 } => Some(Self(0)),
+            Hex { x: 1, y: 1 } => Some(Self(1)),
+            Hex { x: -1, y: 2 } => Some(Self(2)),
+            Hex { x: -2, y: 1 } => Some(Self(3)),
+            Hex { x: -1, y: -1 } => Some(Self(4)),
+            Hex { x: 1, y: -2 } => Some(Self(5)),
+            _ => None,
+        }
+    }
+
     /// Computes the opposite direction of `self`
     ///
     /// # Example
@@ -253,6 +288,32 @@ impl VertexDirection {
         Self((self.0 + 3) % 6)
     }
 
+    /// Returns all directions except the opposite of `self`, in clockwise
+    /// order starting with `self`.
+    ///
+    /// This is useful for movement constraints like units which can't reverse
+    /// direction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let options = VertexDirection::X.non_reverse_options();
+    /// assert_eq!(options[0], VertexDirection::X);
+    /// assert!(!options.contains(&VertexDirection::NEG_X));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn non_reverse_options(self) -> [Self; 5] {
+        [
+            self,
+            self.rotate_cw(1),
+            self.rotate_cw(2),
+            self.rotate_cw(4),
+            self.rotate_cw(5),
+        ]
+    }
+
     /// Returns the next direction in clockwise order
     ///
     /// # Example
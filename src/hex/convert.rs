@@ -1,5 +1,66 @@
 use crate::Hex;
 use glam::{IVec2, IVec3, Vec2};
+use std::{fmt, num::ParseIntError, str::FromStr};
+
+/// Error returned when parsing a [`Hex`] from a string fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseHexError {
+    /// The string is not made of exactly two comma separated coordinates
+    InvalidFormat,
+    /// A coordinate is not a valid integer
+    InvalidCoordinate(ParseIntError),
+}
+
+impl fmt::Display for ParseHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFormat => write!(f, "expected two comma separated coordinates `x,y`"),
+            Self::InvalidCoordinate(e) => write!(f, "invalid coordinate: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseHexError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidFormat => None,
+            Self::InvalidCoordinate(e) => Some(e),
+        }
+    }
+}
+
+/// Formats the coordinate as `x,y`, parsable with [`FromStr`]
+///
+/// ```rust
+/// # use hexx::*;
+/// assert_eq!(hex(3, -12).to_string(), "3,-12");
+/// ```
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Parses a coordinate from the `x,y` format, surrounding whitespace allowed
+///
+/// ```rust
+/// # use hexx::*;
+/// assert_eq!("3,-12".parse(), Ok(hex(3, -12)));
+/// assert_eq!(" 3 , -12 ".parse(), Ok(hex(3, -12)));
+/// assert!("3,-12,9".parse::<Hex>().is_err());
+/// ```
+impl FromStr for Hex {
+    type Err = ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = s.split_once(',').ok_or(ParseHexError::InvalidFormat)?;
+        if y.contains(',') {
+            return Err(ParseHexError::InvalidFormat);
+        }
+        let [x, y] = [x, y].map(|c| c.trim().parse().map_err(ParseHexError::InvalidCoordinate));
+        Ok(Self::new(x?, y?))
+    }
+}
 
 impl From<(i32, i32)> for Hex {
     #[inline]
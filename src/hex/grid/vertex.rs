@@ -1,3 +1,4 @@
+use glam::IVec3;
 use std::ops::Neg;
 
 use crate::{Hex, VertexDirection};
@@ -124,6 +125,96 @@ impl GridVertex {
     }
 }
 
+impl GridVertex {
+    /// Vertex position in cubic space, scaled by 3 to remain integral.
+    ///
+    /// Equivalent vertices share the same key
+    fn position_key(&self) -> IVec3 {
+        self.coordinates().into_iter().map(Hex::as_ivec3).sum()
+    }
+
+    /// Returns the three edges connected to `self`, the two [side
+    /// edges](Self::side_edges) and the edge between the two
+    /// [destinations](Self::destinations)
+    const fn connected_edges(&self) -> [GridEdge; 3] {
+        let [a, b] = self.side_edges();
+        [
+            a,
+            b,
+            GridEdge {
+                origin: self.origin.add_dir(self.direction.edge_ccw()),
+                direction: self.direction.edge_ccw().rotate_cw(2),
+            },
+        ]
+    }
+
+    /// Computes the path along the grid edges from `self` to `rhs`, as close
+    /// as possible to the straight line between the two vertices.
+    ///
+    /// Unlike [`Hex::line_to`] which goes through coordinate centers, this
+    /// walks on the hexagon boundaries, which is useful for edge based
+    /// mechanics like walls or rivers affecting both adjacent coordinates.
+    ///
+    /// The returned edges are ordered from `self` to `rhs` and are oriented
+    /// so that every edge [`origin`](GridEdge::origin) is on the *clockwise*
+    /// side of the path and every [`destination`](GridEdge::destination) on
+    /// the *counter clockwise* side.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let start = GridVertex {
+    ///     origin: hex(0, 0),
+    ///     direction: VertexDirection::FLAT_RIGHT,
+    /// };
+    /// let end = GridVertex {
+    ///     origin: hex(4, -2),
+    ///     direction: VertexDirection::FLAT_LEFT,
+    /// };
+    /// for edge in start.edge_line_to(end) {
+    ///     // The two coordinates sharing the edge
+    ///     let [a, b] = [edge.origin, edge.destination()];
+    /// }
+    /// ```
+    #[must_use]
+    pub fn edge_line_to(self, rhs: Self) -> Vec<GridEdge> {
+        let target = rhs.position_key();
+        let mut current = self;
+        let mut res = Vec::new();
+        loop {
+            let pos = current.position_key();
+            if pos == target {
+                break;
+            }
+            // On an hexagonal grid vertices have 3 neighbors, 120 degrees apart,
+            // so there is always a neighbor closer to the target
+            let Some((edge, next)) = current
+                .connected_edges()
+                .into_iter()
+                .map(|edge| {
+                    let [a, b] = edge.vertices();
+                    let next = if a.position_key() == pos { b } else { a };
+                    (edge, next)
+                })
+                .min_by_key(|(_, v)| (target - v.position_key()).length_squared())
+            else {
+                break;
+            };
+            let side = (next.position_key() - pos)
+                .as_vec3()
+                .cross((edge.origin.as_ivec3() * 3 - pos).as_vec3());
+            res.push(if side.element_sum() > 0.0 {
+                edge
+            } else {
+                edge.flipped()
+            });
+            current = next;
+        }
+        res
+    }
+}
+
 impl Hex {
     /// Returns all vertices of the given coordinate
     #[inline]
@@ -153,3 +244,41 @@ impl From<VertexDirection> for GridVertex {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn edge_line() {
+        let vertices: Vec<_> = Hex::ZERO.range(3).flat_map(Hex::all_vertices).collect();
+        let start = vertices[0];
+        for end in vertices {
+            let edges = start.edge_line_to(end);
+            let mut current = start;
+            for edge in &edges {
+                // Edges are connected
+                let [a, b] = edge.vertices();
+                let next = if a.equivalent(&current) {
+                    b
+                } else {
+                    assert!(b.equivalent(&current));
+                    a
+                };
+                // Edge origins are on the clockwise side
+                let travel = next.position_key() - current.position_key();
+                let side = edge.origin.as_ivec3() * 3 - current.position_key();
+                assert!(travel.cross(side).element_sum() > 0);
+                current = next;
+            }
+            assert!(current.equivalent(&end));
+            // The path doesn't wander away from the straight line
+            let edge_len = 6.0_f32.sqrt();
+            let dist = (end.position_key() - start.position_key())
+                .as_vec3()
+                .length();
+            assert!(edges.len() as f32 <= 2.0 * dist / edge_len + 1.0);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::{
+    collections::HashMap,
+    hash::{BuildHasherDefault, Hasher},
+};
+
+/// Multiplicative constant used to spread the coordinate bits
+const SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Fast [`Hasher`] dedicated to [`Hex`] keys
+///
+/// A [`Hex`] is two `i32`, which are packed in a single `u64` and mixed with
+/// a single multiplication. This is much faster than the default `SipHash`
+/// hasher for hash heavy usage like graph search, but it is not resistant to
+/// `HashDoS` attacks.
+///
+/// Other types are supported but this hasher is only optimized for [`Hex`].
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// use hexx::hex::HexHashMap;
+///
+/// let mut costs: HexHashMap<u32> = HexHashMap::default();
+/// costs.insert(hex(1, 2), 5);
+/// assert_eq!(costs.get(&hex(1, 2)), Some(&5));
+/// ```
+///
+/// [`Hex`]: crate::Hex
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HexHasher {
+    state: u64,
+}
+
+/// [`std::hash::BuildHasher`] for [`HexHasher`]
+pub type BuildHexHasher = BuildHasherDefault<HexHasher>;
+
+/// [`HashMap`] using [`HexHasher`], dedicated to [`Hex`](crate::Hex) keys
+pub type HexHashMap<T> = HashMap<crate::Hex, T, BuildHexHasher>;
+
+impl HexHasher {
+    #[inline]
+    const fn add(&mut self, value: u32) {
+        self.state = self.state.rotate_left(32) ^ value as u64;
+    }
+}
+
+impl Hasher for HexHasher {
+    #[inline]
+    #[allow(clippy::cast_sign_loss)]
+    fn write_i32(&mut self, i: i32) {
+        self.add(i as u32);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add(i);
+    }
+
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(4) {
+            let mut buf = [0; 4];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            self.add(u32::from_ne_bytes(buf));
+        }
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        let hash = self.state.wrapping_mul(SEED);
+        // The high bits are the best mixed, we fold them on the low bits
+        hash ^ (hash >> 32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Hex;
+    use std::{
+        collections::HashSet,
+        hash::{BuildHasher, Hash},
+    };
+
+    #[test]
+    fn distinct_hashes() {
+        let builder = BuildHexHasher::default();
+        let hashes: HashSet<u64> = Hex::ZERO.range(50).map(|h| builder.hash_one(h)).collect();
+        assert_eq!(hashes.len(), Hex::range_count(50) as usize);
+        // Symmetric coordinates
+        assert_ne!(
+            builder.hash_one(Hex::new(1, 2)),
+            builder.hash_one(Hex::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn map_usage() {
+        let mut map: HexHashMap<i32> = HexHashMap::default();
+        for h in Hex::ZERO.range(10) {
+            map.insert(h, h.x - h.y);
+        }
+        for h in Hex::ZERO.range(10) {
+            assert_eq!(map[&h], h.x - h.y);
+        }
+        let mut hasher = HexHasher::default();
+        Hex::new(-3, 7).hash(&mut hasher);
+        let mut other = HexHasher::default();
+        Hex::new(-3, 7).hash(&mut other);
+        assert_eq!(hasher.finish(), other.finish());
+    }
+}
@@ -4,26 +4,34 @@ mod convert;
 /// Hexagonal grid utilities, like edge and vertices
 #[cfg(feature = "grid")]
 pub mod grid;
+/// Dedicated fast hasher
+#[cfg(not(target_arch = "spirv"))]
+mod hash;
 /// Traits implementations
 mod impls;
 /// Iterator tools module
 mod iter;
 /// Hex ring utils
 mod rings;
+#[cfg(feature = "serde")]
+pub mod serde_repr;
 /// swizzle utils
 mod siwzzle;
 #[cfg(test)]
 mod tests;
 
+pub use convert::ParseHexError;
+#[cfg(not(target_arch = "spirv"))]
+pub use hash::{BuildHexHasher, HexHashMap, HexHasher};
 pub(crate) use iter::ExactSizeHexIterator;
 pub use iter::HexIterExt;
 
 use crate::{DirectionWay, EdgeDirection, VertexDirection};
-use glam::{IVec2, IVec3, Vec2};
+use glam::{IVec2, IVec3, Vec2, Vec3};
 #[cfg(feature = "grid")]
 pub use grid::{GridEdge, GridVertex};
 use std::{
-    cmp::{max, min},
+    cmp::{max, min, Ordering},
     fmt::Debug,
 };
 
@@ -52,12 +60,30 @@ use std::{
 ///  * Doubled: use [`Self::from_doubled_coordinates`] and
 ///    [`Self::to_doubled_coordinates`]
 ///
+/// # Constants
+///
+/// Common coordinates are provided as constants, similar to [`IVec2`]:
+///  * [`Self::ZERO`] (also the [`Default`] value), [`Self::ONE`] and
+///    [`Self::NEG_ONE`]
+///  * Axis unit vectors: [`Self::X`], [`Self::NEG_X`], [`Self::Y`] and
+///    [`Self::NEG_Y`]
+///  * The six neighbor deltas: [`Self::NEIGHBORS_COORDS`]
+///  * The six diagonal deltas: [`Self::DIAGONAL_COORDS`]
+///
+/// ```rust
+/// # use hexx::*;
+/// assert_eq!(Hex::default(), Hex::ZERO);
+/// assert_eq!(Hex::ONE * 3, hex(3, 3));
+/// let dir = EdgeDirection::ALL_DIRECTIONS[0];
+/// assert_eq!(Hex::NEIGHBORS_COORDS[0], Hex::ZERO.neighbor(dir));
+/// ```
+///
 /// [comparison]: https://www.redblobgames.com/grids/hexagons/#coordinates-comparison
 /// [axial]: https://www.redblobgames.com/grids/hexagons/#coordinates-axial
 #[derive(Copy, Clone, Default, Eq, PartialEq)]
 #[cfg_attr(not(target_arch = "spirv"), derive(Hash))]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "packed", repr(C))]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 pub struct Hex {
     /// `x` axial coordinate (sometimes called `q` or `i`)
@@ -86,7 +112,7 @@ pub const fn hex(x: i32, y: i32) -> Hex {
 impl Hex {
     /// (0, 0)
     pub const ORIGIN: Self = Self::ZERO;
-    /// (0, 0)
+    /// (0, 0), also the [`Default`] value
     pub const ZERO: Self = Self::new(0, 0);
     /// (1, 1)
     pub const ONE: Self = Self::new(1, 1);
@@ -335,6 +361,34 @@ impl Hex {
         [self.x as f32, self.y as f32, self.z() as f32]
     }
 
+    #[inline]
+    #[must_use]
+    #[doc(alias = "index")]
+    /// Retrieves the cubic coordinate of the given `axis` index, `0` being
+    /// `x`, `1` being `y` and `2` being `z`.
+    ///
+    /// This allows to loop over the three cubic axes generically. [`Hex`]
+    /// can't implement [`Index`] as `z` is not stored but computed.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `axis` is greater than 2, like out of range slice access
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let coord = Hex::new(3, 5);
+    /// assert_eq!(coord.axis(0), 3);
+    /// assert_eq!(coord.axis(1), 5);
+    /// assert_eq!(coord.axis(2), -8);
+    /// ```
+    ///
+    /// [`Index`]: std::ops::Index
+    pub const fn axis(self, axis: usize) -> i32 {
+        self.to_cubic_array()[axis]
+    }
+
     /// Creates a [`Hex`] from the first 2 values in `slice`.
     ///
     /// # Panics
@@ -395,7 +449,29 @@ impl Hex {
     /// This operation is a direct mapping of coordinates.
     /// To convert hex coordinates to world space use [`HexLayout`]
     ///
+    /// This is useful for continuous simulations in hexagonal space, like
+    /// physics: fractional positions can be converted back to the nearest
+    /// coordinate with [`Hex::round`] or to world space with
+    /// [`HexLayout::fract_hex_to_world_pos`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let coord = hex(3, -2);
+    /// let velocity = Vec2::new(0.3, -0.1);
+    /// let pos = coord.as_vec2() + velocity;
+    /// assert_eq!(Hex::round(pos.to_array()), coord);
+    /// // World position of the moving unit
+    /// let layout = HexLayout::default();
+    /// let world_pos = layout.fract_hex_to_world_pos(pos);
+    /// assert!(layout
+    ///     .world_pos_to_fract_hex(world_pos)
+    ///     .abs_diff_eq(pos, 1e-5));
+    /// ```
+    ///
     /// [`HexLayout`]: crate::HexLayout
+    /// [`HexLayout::fract_hex_to_world_pos`]: crate::HexLayout::fract_hex_to_world_pos
     pub const fn as_vec2(self) -> Vec2 {
         Vec2 {
             x: self.x as f32,
@@ -619,6 +695,28 @@ impl Hex {
         self.const_sub(rhs).ulength()
     }
 
+    /// Returns a comparator ordering coordinates by their distance to
+    /// `center`, nearest first, to use with `sort_by` or similar methods.
+    ///
+    /// Coordinates at the same distance are considered equal, so a stable sort
+    /// keeps their original order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(5, 5);
+    /// let mut coords = vec![hex(0, 0), hex(5, 6), hex(8, 5), hex(5, 5)];
+    /// coords.sort_by(Hex::cmp_by_distance(center));
+    /// assert_eq!(coords, vec![hex(5, 5), hex(5, 6), hex(8, 5), hex(0, 0)]);
+    /// ```
+    pub fn cmp_by_distance(center: Self) -> impl FnMut(&Self, &Self) -> Ordering {
+        move |a, b| {
+            a.unsigned_distance_to(center)
+                .cmp(&b.unsigned_distance_to(center))
+        }
+    }
+
     #[inline]
     #[must_use]
     /// Retrieves the hexagonal neighbor coordinates matching the given
@@ -681,6 +779,8 @@ impl Hex {
     /// Retrieves the direction of the given neighbor. Will return `None` if
     /// `other` is not a neighbor of `self`
     ///
+    /// See [`Self::diagonal_neighbor_direction`] for diagonal neighbors
+    ///
     /// # Example
     ///
     /// ```rust
@@ -690,8 +790,28 @@ impl Hex {
     /// let dir = coord.neighbor_direction(bottom).unwrap();
     /// assert_eq!(dir, EdgeDirection::FLAT_BOTTOM);
     /// ```
-    pub fn neighbor_direction(self, other: Self) -> Option<EdgeDirection> {
-        EdgeDirection::iter().find(|&dir| self.neighbor(dir) == other)
+    #[doc(alias = "direction_to")]
+    pub const fn neighbor_direction(self, other: Self) -> Option<EdgeDirection> {
+        EdgeDirection::from_hex_step(other.const_sub(self))
+    }
+
+    #[inline]
+    #[must_use]
+    /// Retrieves the direction of the given diagonal neighbor. Will return
+    /// `None` if `other` is not a diagonal neighbor of `self`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let coord = Hex::new(10, 5);
+    /// let right = coord.diagonal_neighbor(VertexDirection::FLAT_RIGHT);
+    /// let dir = coord.diagonal_neighbor_direction(right).unwrap();
+    /// assert_eq!(dir, VertexDirection::FLAT_RIGHT);
+    /// assert_eq!(coord.diagonal_neighbor_direction(coord), None);
+    /// ```
+    pub const fn diagonal_neighbor_direction(self, other: Self) -> Option<VertexDirection> {
+        VertexDirection::from_hex_step(other.const_sub(self))
     }
 
     #[must_use]
@@ -756,7 +876,26 @@ impl Hex {
 
     #[inline]
     #[must_use]
-    /// Retrieves all 6 neighbor diagonal coordinates around `self`
+    /// Retrieves all 6 neighbor diagonal coordinates around `self`, following
+    /// [`VertexDirection`] order. These are the *corner adjacent* coordinates
+    /// of the second ring.
+    ///
+    /// See [`Self::DIAGONAL_COORDS`] for the deltas
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let coord = hex(3, -2);
+    /// for (diag, dir) in coord
+    ///     .all_diagonals()
+    ///     .into_iter()
+    ///     .zip(VertexDirection::ALL_DIRECTIONS)
+    /// {
+    ///     assert_eq!(diag, coord.diagonal_neighbor(dir));
+    ///     assert_eq!(coord.distance_to(diag), 2);
+    /// }
+    /// ```
     pub fn all_diagonals(self) -> [Self; 6] {
         Self::DIAGONAL_COORDS.map(|n| self.const_add(n))
     }
@@ -804,6 +943,19 @@ impl Hex {
     #[must_use]
     /// Rotates `self` around `center` counter clockwise by `m` (by `-60 * m`
     /// degrees)
+    ///
+    /// `m` is taken modulo 6, and rotating around [`Hex::ZERO`] is equivalent
+    /// to [`Self::rotate_ccw`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(3, -1);
+    /// let p = hex(4, -1);
+    /// assert_eq!(p.rotate_ccw_around(center, 1), hex(4, -2));
+    /// assert_eq!(p.rotate_ccw_around(center, 6), p);
+    /// ```
     pub const fn rotate_ccw_around(self, center: Self, m: u32) -> Self {
         self.const_sub(center).rotate_ccw(m).const_add(center)
     }
@@ -850,10 +1002,49 @@ impl Hex {
     #[inline]
     #[must_use]
     /// Rotates `self` around `center` clockwise by `m` (by `60 * m` degrees)
+    ///
+    /// `m` is taken modulo 6, and rotating around [`Hex::ZERO`] is equivalent
+    /// to [`Self::rotate_cw`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(3, -1);
+    /// let p = hex(4, -1);
+    /// assert_eq!(p.rotate_cw_around(center, 1), hex(3, 0));
+    /// assert_eq!(p.rotate_cw_around(center, 6), p);
+    /// ```
     pub const fn rotate_cw_around(self, center: Self, m: u32) -> Self {
         self.const_sub(center).rotate_cw(m).const_add(center)
     }
 
+    /// Rotates every coordinate of `coords` in place around `center`
+    /// clockwise by `m` (by `60 * m` degrees).
+    ///
+    /// This is equivalent to calling [`Self::rotate_cw_around`] on each
+    /// coordinate, but the rotation is computed only once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(2, 1);
+    /// let mut shape = [hex(3, 1), hex(4, 0), hex(2, 3)];
+    /// let expected = shape.map(|h| h.rotate_cw_around(center, 2));
+    /// Hex::rotate_all_cw(&mut shape, 2, center);
+    /// assert_eq!(shape, expected);
+    /// ```
+    pub fn rotate_all_cw(coords: &mut [Self], m: u32, center: Self) {
+        // Rotation is linear, so we only rotate the axis unit vectors
+        let x = Self::X.rotate_cw(m);
+        let y = Self::Y.rotate_cw(m);
+        for coord in coords {
+            let delta = *coord - center;
+            *coord = center + x * delta.x + y * delta.y;
+        }
+    }
+
     #[inline]
     #[must_use]
     #[doc(alias = "reflect_q")]
@@ -878,10 +1069,61 @@ impl Hex {
         Self::new(self.y, self.x)
     }
 
+    #[must_use]
+    /// Computes the reflection of `self` accross the line passing through `a`
+    /// and `b`.
+    ///
+    /// Only the 6 symmetry axes of the grid map coordinates to coordinates, so
+    /// the line must follow an [`EdgeDirection`] or a [`VertexDirection`]:
+    /// `b - a` must be a multiple of a neighbor or diagonal coordinate.
+    /// Otherwise, or if `a == b`, `None` is returned.
+    ///
+    /// See [`Self::reflect_x`], [`Self::reflect_y`] and [`Self::reflect_z`]
+    /// for reflections through the origin.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let [a, b] = [hex(1, 1), hex(4, 1)];
+    /// let p = hex(2, 3);
+    /// let reflected = p.reflect_over_line(a, b).unwrap();
+    /// assert_eq!(reflected, hex(4, -1));
+    /// assert_eq!(reflected.reflect_over_line(a, b), Some(p));
+    /// // Not a symmetry axis
+    /// assert!(p.reflect_over_line(a, hex(3, 2)).is_none());
+    /// ```
+    pub fn reflect_over_line(self, a: Self, b: Self) -> Option<Self> {
+        let delta = b - a;
+        // The 6 reflections through the origin, each fixing one axis
+        let reflections = [
+            Self::reflect_x,
+            Self::reflect_y,
+            Self::reflect_z,
+            |h: Self| -h.reflect_x(),
+            |h: Self| -h.reflect_y(),
+            |h: Self| -h.reflect_z(),
+        ];
+        if delta == Self::ZERO {
+            return None;
+        }
+        reflections
+            .into_iter()
+            .find(|reflect| reflect(delta) == delta)
+            .map(|reflect| a + reflect(self - a))
+    }
+
     #[allow(clippy::cast_precision_loss)]
     #[must_use]
     /// Computes all coordinates in a line from `self` to `other`.
     ///
+    /// The line is computed by linear interpolation of the cubic coordinates,
+    /// rounded with [`Self::round`]. It includes both `self` and `other`, and
+    /// yields exactly `self.unsigned_distance_to(other) + 1` coordinates, each
+    /// one being a neighbor of the previous one.
+    ///
+    /// Useful for line of sight, beam attacks or roads.
+    ///
     /// # Example
     /// ```rust
     /// # use hexx::*;
@@ -903,6 +1145,33 @@ impl Hex {
         }
     }
 
+    #[must_use]
+    /// Computes the sequence of [`EdgeDirection`] steps to walk the
+    /// [`Self::line_to`] line from `self` to `other`.
+    ///
+    /// Applying the directions in sequence from `self` lands exactly on
+    /// `other`, the line has `self.unsigned_distance_to(other)` steps. This is
+    /// useful to store or replay moves compactly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let start = hex(1, 2);
+    /// let end = hex(4, -3);
+    /// let steps = start.directions_to(end);
+    /// assert_eq!(steps.len(), 5);
+    /// let arrival = steps.into_iter().fold(start, Hex::neighbor);
+    /// assert_eq!(arrival, end);
+    /// ```
+    pub fn directions_to(self, other: Self) -> Vec<EdgeDirection> {
+        let line: Vec<Self> = self.line_to(other).collect();
+        // Every line coordinate is a neighbor of the previous one
+        line.windows(2)
+            .filter_map(|w| w[0].neighbor_direction(w[1]))
+            .collect()
+    }
+
     #[allow(clippy::cast_sign_loss)]
     #[must_use]
     /// Computes all coordinate in a two segment rectiline path from `self` to
@@ -954,13 +1223,50 @@ impl Hex {
         }
     }
 
+    /// Computes all coordinates in a *thick* line from `self` to `other`: every
+    /// coordinate within `width` of the [`Self::line_to`] line.
+    ///
+    /// The distance to the line is the hex distance to its closest coordinate,
+    /// so a `width` of 0 gives the regular line and a `width` of 1 adds a
+    /// coordinate on each side. Each coordinate is yielded once, ordered by
+    /// progress along the line.
+    ///
+    /// Useful for road corridors or wide beam attacks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let beam: Vec<Hex> = Hex::ZERO.thick_line_to(hex(5, 0), 1).collect();
+    /// assert_eq!(beam.len(), 22);
+    /// assert!(beam.contains(&hex(3, -1)));
+    /// assert!(!beam.contains(&hex(3, -2)));
+    /// ```
+    #[doc(alias = "thick_line")]
+    pub fn thick_line_to(self, other: Self, width: u32) -> impl Iterator<Item = Self> {
+        let line: Vec<Self> = self.line_to(other).collect();
+        // Two coordinates of the line are within `2 * width` of a common coordinate
+        // only if they are within `2 * width` steps of each other
+        let reach = 2 * width as usize;
+        (0..line.len()).flat_map(move |i| {
+            let previous = line[i.saturating_sub(reach)..i].to_vec();
+            line[i]
+                .range(width)
+                .filter(move |h| previous.iter().all(|p| p.unsigned_distance_to(*h) > width))
+        })
+    }
+
     /// Performs a linear interpolation between `self` and `rhs` based on the
     /// value `s`.
     ///
     /// When `s` is `0.0`, the result will be equal to `self`.  When `s` is
     /// `1.0`, the result will be equal to `rhs`. When `s` is outside of
     /// range `[0, 1]`, the result is linearly extrapolated.
-    #[doc(alias = "mix")]
+    ///
+    /// The result is the rounded value of [`Self::lerp_cubic`], sampling `s`
+    /// in `n` regular steps with `n = self.unsigned_distance_to(rhs)` matches
+    /// [`Self::line_to`].
+    #[doc(alias = "mix", alias = "round_lerp")]
     #[inline]
     #[must_use]
     pub fn lerp(self, rhs: Self, s: f32) -> Self {
@@ -968,6 +1274,97 @@ impl Hex {
         start.lerp(end, s).into()
     }
 
+    /// Performs a linear interpolation between `self` and `rhs` based on the
+    /// value `s`, returning fractional cubic coordinates `[x, y, z]`.
+    ///
+    /// Useful for smooth movement in hexagonal space, the result can be
+    /// rounded at any time with [`Self::round_cubic`]. See [`Self::lerp`] for
+    /// a rounded interpolation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let [a, b] = [hex(0, 0), hex(4, -2)];
+    /// assert_eq!(a.lerp_cubic(b, 0.5), [2.0, -1.0, -1.0]);
+    /// let cubic = a.lerp_cubic(b, 0.3);
+    /// assert_eq!(Hex::round_cubic(cubic), a.lerp(b, 0.3));
+    /// ```
+    #[doc(alias = "mix_cubic")]
+    #[inline]
+    #[must_use]
+    pub fn lerp_cubic(self, rhs: Self, s: f32) -> [f32; 3] {
+        let [start, end]: [Vec3; 2] =
+            [self.to_cubic_array_f32(), rhs.to_cubic_array_f32()].map(Vec3::from_array);
+        start.lerp(end, s).to_array()
+    }
+
+    /// Computes the midpoint between `self` and `rhs`, rounded to the nearest
+    /// coordinate.
+    ///
+    /// Equivalent to `self.lerp(rhs, 0.5)`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// assert_eq!(Hex::midpoint(hex(0, 0), hex(4, -2)), hex(2, -1));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        self.lerp(rhs, 0.5)
+    }
+
+    /// Computes the centroid of `hexes`, as fractional cubic coordinates
+    /// `[x, y, z]`, or `None` if `hexes` is empty.
+    ///
+    /// The centroid is the average of all coordinates, use
+    /// [`Self::round_cubic`] to snap it to the nearest coordinate.
+    ///
+    /// > See also [`HexIterExt::average`] for a rounded integer average
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let centroid = Hex::centroid([hex(0, 0), hex(2, 0), hex(0, 2)]);
+    /// assert!(centroid.is_some());
+    /// assert_eq!(Hex::centroid([]), None);
+    /// let region = hex(3, -5).range(4);
+    /// let centroid = Hex::centroid(region).map(Hex::round_cubic);
+    /// assert_eq!(centroid, Some(hex(3, -5)));
+    /// ```
+    #[must_use]
+    #[doc(alias = "barycenter")]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn centroid(hexes: impl IntoIterator<Item = Self>) -> Option<[f32; 3]> {
+        let (sum, count) = hexes
+            .into_iter()
+            .fold((Vec3::ZERO, 0_u32), |(sum, count), h| {
+                (sum + Vec3::from_array(h.to_cubic_array_f32()), count + 1)
+            });
+        (count > 0).then(|| (sum / count as f32).to_array())
+    }
+
+    /// Rounds fractional cubic coordinates `[x, y, z]` to the nearest
+    /// coordinate.
+    ///
+    /// The `z` coordinate is expected to equal `-x - y` and is ignored, see
+    /// [`Self::round`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// assert_eq!(Hex::round_cubic([0.6, 10.2, -10.8]), hex(1, 10));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn round_cubic([x, y, _]: [f32; 3]) -> Self {
+        Self::round([x, y])
+    }
+
     #[allow(clippy::cast_possible_wrap)]
     #[must_use]
     /// Retrieves all [`Hex`] around `self` in a given `range`.
@@ -995,6 +1392,47 @@ impl Hex {
         }
     }
 
+    #[allow(clippy::cast_possible_wrap)]
+    #[must_use]
+    /// Retrieves all [`Hex`] around `self` in a given `range`, in *row major*
+    /// order.
+    ///
+    /// Unlike [`Hex::range`], coordinates are ordered by `y` first and then by
+    /// `x`, both increasing: every row of constant `y` is contiguous. This
+    /// matches the storage order of
+    /// [`HexagonalMap`](crate::storage::HexagonalMap), allowing cache friendly
+    /// processing of every coordinate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let coords: Vec<_> = hex(0, 0).row_major_range(1).collect();
+    /// assert_eq!(
+    ///     coords,
+    ///     vec![
+    ///         hex(0, -1),
+    ///         hex(1, -1),
+    ///         hex(-1, 0),
+    ///         hex(0, 0),
+    ///         hex(1, 0),
+    ///         hex(-1, 1),
+    ///         hex(0, 1),
+    ///     ]
+    /// );
+    /// ```
+    pub fn row_major_range(self, range: u32) -> impl ExactSizeIterator<Item = Self> {
+        let radius = range as i32;
+        ExactSizeHexIterator {
+            iter: (-radius..=radius).flat_map(move |y| {
+                let x_min = max(-radius, -y - radius);
+                let x_max = min(radius, radius - y);
+                (x_min..=x_max).map(move |x| self.const_add(Self::new(x, y)))
+            }),
+            count: Self::range_count(range) as usize,
+        }
+    }
+
     #[allow(clippy::cast_possible_wrap)]
     #[doc(alias = "excluding_range")]
     #[must_use]
@@ -1176,6 +1614,31 @@ impl Hex {
     pub fn wrap_in_range(self, range: u32) -> Self {
         self.to_local(range)
     }
+
+    #[inline]
+    #[must_use]
+    /// Wraps `self` in a parallelogram of `size` with its lowest coordinate at
+    /// the origin ([`Hex::ZERO`]), reducing both axes modulo `size`.
+    ///
+    /// Unlike [`Self::wrap_in_range`], which wraps in an hexagonal (toroidal)
+    /// domain, this is the *rhombic* tiling variant, useful for repeating
+    /// patterns or tileable noise.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if any `size` axis is zero
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let size = Hex::new(4, 3);
+    /// assert_eq!(Hex::new(5, 7).wrap_in_parallelogram(size), Hex::new(1, 1));
+    /// assert_eq!(Hex::new(-1, -1).wrap_in_parallelogram(size), Hex::new(3, 2));
+    /// ```
+    pub const fn wrap_in_parallelogram(self, size: Self) -> Self {
+        Self::new(self.x.rem_euclid(size.x), self.y.rem_euclid(size.y))
+    }
 }
 
 #[cfg(not(target_arch = "spirv"))]
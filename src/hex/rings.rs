@@ -9,9 +9,36 @@ impl Hex {
     ///
     /// > If you only need the coordinates see [`Self::ring`]
     ///
+    /// # Ordering
+    ///
+    /// The ring goes through its six *corners* `self + dir * range` following
+    /// the [`EdgeDirection`] order, starting with `start_dir`: the `i`th
+    /// corner is in direction `start_dir + i` (`start_dir - i` if
+    /// `clockwise`).
+    ///
+    /// With `start_dir` set to [`EdgeDirection::default`], this matches the
+    /// ordering of [`HexLayout::hex_edge_corners`] and of the
+    /// [`ColumnMeshBuilder`] sides, allowing to align per-ring and per-side
+    /// data.
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let start = EdgeDirection::FLAT_TOP;
+    /// let ring: Vec<Hex> = Hex::ZERO.custom_ring(3, start, false).collect();
+    /// assert_eq!(ring[0], start * 3);
+    /// assert_eq!(ring[3], (start + 1) * 3);
+    /// // Same start, opposite winding
+    /// let cw_ring: Vec<Hex> = Hex::ZERO.custom_ring(3, start, true).collect();
+    /// assert_eq!(cw_ring[0], start * 3);
+    /// assert_eq!(cw_ring[3], (start - 1) * 3);
+    /// ```
+    ///
     /// # Note
     /// The returned iterator will have `6 * range` ([`Self::ring_count`])
     /// items, unless `range` is 0 which will return `self`
+    ///
+    /// [`HexLayout::hex_edge_corners`]: crate::HexLayout::hex_edge_corners
+    /// [`ColumnMeshBuilder`]: crate::ColumnMeshBuilder
     pub fn custom_ring(
         self,
         range: u32,
@@ -47,9 +74,10 @@ impl Hex {
     #[must_use]
     /// Retrieves one [`Hex`] ring around `self` in a given `range`.
     /// The returned coordinates start from [`EdgeDirection::default`]
-    /// and loop around `self` counter clockwise.
+    /// and loop around `self` counter clockwise, following the
+    /// [`EdgeDirection`] order.
     ///
-    /// > See [`Self::custom_ring`] for more options.
+    /// > See [`Self::custom_ring`] for more options and ordering details.
     ///
     /// # Note
     /// The returned iterator will have `6 * range` ([`Self::ring_count`])
@@ -58,6 +86,42 @@ impl Hex {
         self.custom_ring(range, EdgeDirection::default(), false)
     }
 
+    /// Checks if `self` is on the ring of the given `radius` around `center`,
+    /// without enumerating the ring.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(1, 2);
+    /// assert!(hex(4, 2).is_on_ring(center, 3));
+    /// assert!(!hex(3, 2).is_on_ring(center, 3));
+    /// assert!(center.is_on_ring(center, 0));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_on_ring(self, center: Self, radius: u32) -> bool {
+        self.unsigned_distance_to(center) == radius
+    }
+
+    /// Checks if `self` is in the hexagonal range (disk) of the given `radius`
+    /// around `center`, without enumerating the range.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(1, 2);
+    /// assert!(hex(4, 2).is_in_range(center, 3));
+    /// assert!(hex(3, 2).is_in_range(center, 3));
+    /// assert!(!hex(5, 2).is_in_range(center, 3));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_in_range(self, center: Self, radius: u32) -> bool {
+        self.unsigned_distance_to(center) <= radius
+    }
+
     /// Retrieves `range` [`Hex`] rings around `self` in a given `range`.
     /// The returned coordinates start from [`EdgeDirection::default`]
     /// and loop around `self` counter clockwise.
@@ -76,6 +140,31 @@ impl Hex {
         range.map(move |r| self.ring(r).collect())
     }
 
+    /// Retrieves `range` [`Hex`] rings around `self` in a given `range`, paired
+    /// with their radius.
+    /// The returned coordinates follow the [`Self::ring`] ordering, and the
+    /// ring of radius `0` is `self`.
+    ///
+    /// This is useful to process concentric bands around a coordinate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(2, 1);
+    /// let rings: Vec<(u32, Vec<Hex>)> = center.rings_with_radius(0..=3).collect();
+    /// assert_eq!(rings[0], (0, vec![center]));
+    /// for (radius, ring) in rings {
+    ///     assert_eq!(ring.len(), Hex::ring_count(radius));
+    /// }
+    /// ```
+    pub fn rings_with_radius(
+        self,
+        range: impl Iterator<Item = u32>,
+    ) -> impl Iterator<Item = (u32, Vec<Self>)> {
+        range.map(move |r| (r, self.ring(r).collect()))
+    }
+
     /// Retrieves `range` [`Hex`] rings around `self` in a given `range`.
     /// The returned coordinates start from `start_dir` and loop around `self`
     /// counter clockwise unless `clockwise` is set to true.
@@ -509,8 +598,28 @@ impl Hex {
     /// successive rings, starting from `start_dir` and looping counter
     /// clockwise unless `clockwise` is set to `true`, forming a spiral
     ///
+    /// Every ring follows the [`Self::custom_ring`] ordering, so all rings
+    /// start in the same `start_dir` and wind the same way. For a `0..=range`
+    /// spiral, there are exactly [`Self::range_count`] coordinates.
+    ///
     /// If you only need the coordinates see [`Self::spiral_range`].
     ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let start = EdgeDirection::FLAT_TOP;
+    /// let spiral: Vec<Hex> = Hex::ZERO
+    ///     .custom_spiral_range(0..=3, start, true)
+    ///     .collect();
+    /// assert_eq!(spiral.len(), Hex::range_count(3) as usize);
+    /// // Center, then the first ring starting at `start`
+    /// assert_eq!(spiral[0], Hex::ZERO);
+    /// assert_eq!(spiral[1], start.into());
+    /// // The second ring also starts at `start`
+    /// assert_eq!(spiral[7], start * 2);
+    /// ```
+    ///
     /// See this [article](https://www.redblobgames.com/grids/hexagons/#rings-spiral) for more
     /// information
     pub fn custom_spiral_range(
@@ -523,7 +632,7 @@ impl Hex {
     }
 
     /// Retrieves all [`Hex`] around `self` in a given `range` but ordered as
-    /// successive rings, starting from [`EdgeDirection::FLAT_TOP_RIGHT`] and
+    /// successive rings, starting from [`EdgeDirection::default`] and
     /// looping counter clockwise, forming a spiral.
     ///
     /// See [`Self::custom_spiral_range`] for more options
@@ -534,6 +643,37 @@ impl Hex {
         self.custom_spiral_range(range, EdgeDirection::default(), false)
     }
 
+    /// Retrieves a walkable path visiting every [`Hex`] around `self` up to
+    /// `max_radius`, as an outward spiral.
+    ///
+    /// Unlike [`Self::spiral_range`], every returned coordinate is a neighbor
+    /// of the previous one: connecting steps are inserted between rings, so
+    /// some coordinates may be visited twice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let center = hex(2, -1);
+    /// let path: Vec<_> = center.spiral_walk(3).collect();
+    /// assert_eq!(path[0], center);
+    /// for pair in path.windows(2) {
+    ///     assert_eq!(pair[0].unsigned_distance_to(pair[1]), 1);
+    /// }
+    /// ```
+    pub fn spiral_walk(self, max_radius: u32) -> impl Iterator<Item = Self> {
+        std::iter::once(self).chain(
+            self.spiral_range(1..=max_radius)
+                .scan(self, |previous, hex| {
+                    // Ring coordinates are adjacent, except at ring transitions
+                    let steps = previous.line_to(hex).skip(1);
+                    *previous = hex;
+                    Some(steps)
+                })
+                .flatten(),
+        )
+    }
+
     #[inline]
     #[must_use]
     /// Counts how many coordinates there are in a ring at the given `range`
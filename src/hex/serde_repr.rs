@@ -0,0 +1,197 @@
+//! Serde implementations and alternative representations of [`Hex`]
+//!
+//! By default [`Hex`] is serialized as a compact axial `[x, y]` array, and can
+//! be deserialized from either that array or the `{ "x": 1, "y": 2 }` struct
+//! representation of previous versions for human readable formats.
+//!
+//! These modules allow to choose a different representation through the
+//! `#[serde(with = "..")]` attribute:
+//!
+//! * [`axial`] serializes as a compact `[x, y]` array, like the default
+//! * [`cubic`] serializes as a `[x, y, z]` array, matching most external tools.
+//!   This representation is self validating as the three coordinates must sum
+//!   to zero.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use hexx::*;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Tile {
+//!     #[serde(with = "hexx::hex::serde_repr::cubic")]
+//!     coord: Hex,
+//!     #[serde(with = "hexx::hex::serde_repr::axial")]
+//!     parent: Hex,
+//! }
+//! ```
+use super::Hex;
+use serde::{
+    de::{Error, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::fmt;
+
+impl Serialize for Hex {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        axial::serialize(self, serializer)
+    }
+}
+
+/// Field names of the legacy `{ "x": 1, "y": 2 }` representation
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    X,
+    Y,
+}
+
+/// [`Hex`] visitor accepting both the `[x, y]` and the `{ "x": 1, "y": 2 }`
+/// representations
+struct HexVisitor;
+
+impl<'de> Visitor<'de> for HexVisitor {
+    type Value = Hex;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an axial `[x, y]` array or a `{ \"x\": 1, \"y\": 2 }` map")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Hex, A::Error> {
+        let x = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+        let y = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+        if seq.next_element::<i32>()?.is_some() {
+            return Err(A::Error::invalid_length(3, &self));
+        }
+        Ok(Hex::new(x, y))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Hex, A::Error> {
+        let [mut x, mut y] = [None; 2];
+        while let Some(field) = map.next_key()? {
+            let value = match field {
+                Field::X => &mut x,
+                Field::Y => &mut y,
+            };
+            if value.replace(map.next_value()?).is_some() {
+                return Err(A::Error::custom("duplicate coordinate field"));
+            }
+        }
+        let x = x.ok_or_else(|| A::Error::missing_field("x"))?;
+        let y = y.ok_or_else(|| A::Error::missing_field("y"))?;
+        Ok(Hex::new(x, y))
+    }
+}
+
+impl<'de> Deserialize<'de> for Hex {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Self describing formats may use the legacy struct representation
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HexVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, HexVisitor)
+        }
+    }
+}
+
+/// Serializes [`Hex`] as an axial `[x, y]` array
+pub mod axial {
+    use super::{Deserialize, Deserializer, Hex, Serialize, Serializer};
+
+    /// Serializes `hex` as `[x, y]`
+    ///
+    /// # Errors
+    ///
+    /// Returns the `serializer` errors
+    pub fn serialize<S: Serializer>(hex: &Hex, serializer: S) -> Result<S::Ok, S::Error> {
+        hex.to_array().serialize(serializer)
+    }
+
+    /// Deserializes a [`Hex`] from `[x, y]`
+    ///
+    /// # Errors
+    ///
+    /// Returns the `deserializer` errors
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hex, D::Error> {
+        <[i32; 2]>::deserialize(deserializer).map(Hex::from_array)
+    }
+}
+
+/// Serializes [`Hex`] as a cubic `[x, y, z]` array
+pub mod cubic {
+    use super::{Deserialize, Deserializer, Hex, Serialize, Serializer};
+    use serde::de::Error;
+
+    /// Serializes `hex` as `[x, y, z]`
+    ///
+    /// # Errors
+    ///
+    /// Returns the `serializer` errors
+    pub fn serialize<S: Serializer>(hex: &Hex, serializer: S) -> Result<S::Ok, S::Error> {
+        hex.to_cubic_array().serialize(serializer)
+    }
+
+    /// Deserializes a [`Hex`] from `[x, y, z]`
+    ///
+    /// # Errors
+    ///
+    /// Returns the `deserializer` errors, or an error if the coordinates don't
+    /// sum to zero
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Hex, D::Error> {
+        let [x, y, z] = <[i32; 3]>::deserialize(deserializer)?;
+        if i64::from(x) + i64::from(y) + i64::from(z) != 0 {
+            return Err(D::Error::custom(
+                "invalid cubic coordinates, `x + y + z` must be zero",
+            ));
+        }
+        Ok(Hex::new(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::{
+        value::{Error, SeqDeserializer},
+        IntoDeserializer,
+    };
+
+    fn seq(values: &[i32]) -> SeqDeserializer<std::vec::IntoIter<i32>, Error> {
+        values.to_vec().into_deserializer()
+    }
+
+    #[test]
+    fn deserialize() {
+        let coord = Hex::new(3, -5);
+        assert_eq!(axial::deserialize(seq(&[3, -5])), Ok(coord));
+        assert_eq!(cubic::deserialize(seq(&[3, -5, 2])), Ok(coord));
+        assert!(cubic::deserialize(seq(&[3, -5, 1])).is_err());
+        assert!(cubic::deserialize(seq(&[i32::MAX, i32::MAX, 2])).is_err());
+        assert!(axial::deserialize(seq(&[3, -5, 2])).is_err());
+    }
+
+    #[test]
+    fn default_representation() {
+        let coord = Hex::new(2, -3);
+        let json = serde_json::to_string(&coord).expect("Failed to serialize");
+        assert_eq!(json, "[2,-3]");
+        assert_eq!(serde_json::from_str::<Hex>(&json).ok(), Some(coord));
+        // Legacy struct representation
+        let legacy = serde_json::from_str::<Hex>(r#"{ "y": -3, "x": 2 }"#);
+        assert_eq!(legacy.ok(), Some(coord));
+        // Invalid values
+        for json in ["[2]", "[2,-3,1]", r#"{ "x": 2 }"#, r#"{ "x": 2, "x": 1 }"#] {
+            assert!(serde_json::from_str::<Hex>(json).is_err());
+        }
+        // Both representations are mentioned in errors
+        let error = serde_json::from_str::<Hex>("true").expect_err("Invalid hex");
+        assert!(error.to_string().contains("`[x, y]` array or a"));
+        // Non self describing formats use the array representation
+        assert_eq!(Hex::deserialize(seq(&[2, -3])), Ok(coord));
+    }
+}
@@ -1,5 +1,6 @@
 #![allow(clippy::unwrap_used)]
 use super::*;
+use std::collections::HashSet;
 
 #[test]
 fn hex_addition() {
@@ -211,6 +212,11 @@ fn diagonals() {
             Hex::new(-1, 3),
         ]
     );
+    // Diagonals follow the vertex directions order
+    for (i, dir) in VertexDirection::ALL_DIRECTIONS.into_iter().enumerate() {
+        assert_eq!(Hex::DIAGONAL_COORDS[i], dir.into_hex());
+        assert_eq!(Hex::ONE.all_diagonals()[i], Hex::ONE.diagonal_neighbor(dir));
+    }
 }
 
 #[test]
@@ -286,6 +292,74 @@ fn rotate_ccw() {
     assert_eq!(point.rotate_ccw(10), point.rotate_ccw(4));
 }
 
+#[test]
+fn rotate_around() {
+    let center = Hex::new(-4, 9);
+    for coord in center.range(5) {
+        for m in 0..12 {
+            assert_eq!(coord.rotate_cw_around(Hex::ZERO, m), coord.rotate_cw(m));
+            assert_eq!(coord.rotate_ccw_around(Hex::ZERO, m), coord.rotate_ccw(m));
+            let cw = coord.rotate_cw_around(center, m);
+            let ccw = coord.rotate_ccw_around(center, m);
+            assert_eq!(
+                cw.unsigned_distance_to(center),
+                coord.unsigned_distance_to(center)
+            );
+            assert_eq!(cw.rotate_ccw_around(center, m), coord);
+            assert_eq!(ccw.rotate_cw_around(center, m), coord);
+            assert_eq!(coord.rotate_cw_around(center, m + 6), cw);
+        }
+        let full_turn = (0..6).fold(coord, |h, _| h.cw_around(center));
+        assert_eq!(full_turn, coord);
+    }
+}
+
+#[test]
+fn reflections() {
+    let axes: Vec<Hex> = Hex::NEIGHBORS_COORDS
+        .into_iter()
+        .chain(Hex::DIAGONAL_COORDS)
+        .collect();
+    let a = Hex::new(-2, 5);
+    for coord in a.range(5) {
+        assert_eq!(coord.reflect_x().reflect_x(), coord);
+        assert_eq!(coord.reflect_y().reflect_y(), coord);
+        assert_eq!(coord.reflect_z().reflect_z(), coord);
+        for axis in &axes {
+            let b = a + *axis * 3;
+            let reflected = coord.reflect_over_line(a, b).unwrap();
+            assert_eq!(reflected.reflect_over_line(a, b), Some(coord));
+            assert_eq!(
+                reflected.unsigned_distance_to(a),
+                coord.unsigned_distance_to(a)
+            );
+            assert_eq!(
+                reflected.unsigned_distance_to(b),
+                coord.unsigned_distance_to(b)
+            );
+            // Points on the line are unchanged
+            assert_eq!(b.reflect_over_line(a, b), Some(b));
+        }
+        assert_eq!(coord.reflect_over_line(a, a), None);
+    }
+}
+
+#[test]
+fn rotate_all_cw() {
+    let center = Hex::new(-3, 7);
+    let shape: Vec<_> = Hex::new(1, 2).range(4).collect();
+    for m in 0..=12 {
+        let mut rotated = shape.clone();
+        Hex::rotate_all_cw(&mut rotated, m, center);
+        for (h, r) in shape.iter().zip(&rotated) {
+            assert_eq!(h.rotate_cw_around(center, m), *r);
+        }
+    }
+    let mut rotated = shape.clone();
+    Hex::rotate_all_cw(&mut rotated, 6, center);
+    assert_eq!(rotated, shape);
+}
+
 #[test]
 fn lerp() {
     let a = Hex::new(0, 0);
@@ -318,6 +392,104 @@ fn lerp() {
     assert_eq!(a.lerp(b, 1.0), line[5]);
 }
 
+#[test]
+#[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+fn lerp_cubic() {
+    let a = Hex::new(-2, 3);
+    for b in a.range(8) {
+        assert_eq!(a.lerp_cubic(b, 0.0), a.to_cubic_array_f32());
+        assert_eq!(a.lerp_cubic(b, 1.0), b.to_cubic_array_f32());
+        let dist = a.unsigned_distance_to(b).max(1) as f32;
+        let rounded = (0..=a.unsigned_distance_to(b)).map(|step| {
+            let [x, y, z] = a.lerp_cubic(b, step as f32 / dist);
+            assert!((x + y + z).abs() < 1e-5);
+            let res = Hex::round([x, y]);
+            assert_eq!(res, a.lerp(b, step as f32 / dist));
+            res
+        });
+        assert!(rounded.eq(a.line_to(b)));
+    }
+}
+
+#[test]
+fn midpoint() {
+    let a = Hex::new(-2, 3);
+    for b in a.range(8) {
+        let mid = a.midpoint(b);
+        assert_eq!(mid, Hex::round_cubic(a.lerp_cubic(b, 0.5)));
+        let dist = a.unsigned_distance_to(b);
+        assert!(a.unsigned_distance_to(mid) <= dist.div_ceil(2));
+        assert!(b.unsigned_distance_to(mid) <= dist.div_ceil(2));
+    }
+    assert_eq!(Hex::midpoint(a, a), a);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn centroid() {
+    assert_eq!(Hex::centroid(std::iter::empty()), None);
+    let a = Hex::new(-2, 3);
+    assert_eq!(Hex::centroid([a]), Some(a.to_cubic_array_f32()));
+    for radius in 0..10 {
+        let centroid = Hex::centroid(a.range(radius)).unwrap();
+        let [x, y, z] = centroid;
+        assert!((x + y + z).abs() < 1e-4);
+        assert_eq!(Hex::round_cubic(centroid), a);
+    }
+    let centroid = Hex::centroid([Hex::ZERO, Hex::new(3, 0), Hex::new(0, 3)]);
+    assert_eq!(centroid, Some([1.0, 1.0, -2.0]));
+}
+
+#[test]
+fn line_to_properties() {
+    let origin = Hex::new(-2, 3);
+    for target in origin.range(8) {
+        let line: Vec<_> = origin.line_to(target).collect();
+        assert_eq!(line.len(), origin.unsigned_distance_to(target) as usize + 1);
+        assert_eq!(line.first(), Some(&origin));
+        assert_eq!(line.last(), Some(&target));
+        for pair in line.windows(2) {
+            assert_eq!(pair[0].unsigned_distance_to(pair[1]), 1);
+        }
+    }
+}
+
+#[test]
+fn thick_line_to() {
+    let origin = Hex::new(1, -2);
+    for target in origin.range(6) {
+        let line: Vec<_> = origin.line_to(target).collect();
+        for width in 0..4 {
+            let thick: Vec<_> = origin.thick_line_to(target, width).collect();
+            let expected: HashSet<_> = origin
+                .range(6 + width)
+                .filter(|h| line.iter().any(|l| l.unsigned_distance_to(*h) <= width))
+                .collect();
+            assert_eq!(thick.len(), expected.len());
+            assert_eq!(thick.iter().copied().collect::<HashSet<_>>(), expected);
+        }
+    }
+}
+
+#[test]
+fn directions_to() {
+    assert!(Hex::ONE.directions_to(Hex::ONE).is_empty());
+    for start in Hex::ZERO.range(2) {
+        for end in Hex::new(-3, 7).range(4) {
+            let steps = start.directions_to(end);
+            assert_eq!(steps.len(), start.unsigned_distance_to(end) as usize);
+            let walk: Vec<Hex> = steps
+                .iter()
+                .scan(start, |p, dir| {
+                    *p = p.neighbor(*dir);
+                    Some(*p)
+                })
+                .collect();
+            assert_eq!(walk, start.line_to(end).skip(1).collect::<Vec<_>>());
+        }
+    }
+}
+
 #[test]
 fn line_to() {
     let a = Hex::new(0, 0);
@@ -415,6 +587,51 @@ fn range() {
     assert_eq!(range.size_hint(), (range.len(), Some(range.len())));
 }
 
+#[test]
+fn row_major_range() {
+    use crate::storage::{HexStore, HexagonalMap};
+
+    let center = Hex::new(-4, 7);
+    for radius in 0..8 {
+        let rows: Vec<_> = center.row_major_range(radius).collect();
+        assert_eq!(rows.len(), Hex::range_count(radius) as usize);
+        let mut expected: Vec<_> = center.range(radius).collect();
+        expected.sort_by_key(|h| (h.y, h.x));
+        assert_eq!(rows, expected);
+        let map = HexagonalMap::new(center, radius, |_| ());
+        let stored: Vec<_> = map.iter().map(|(h, ())| h).collect();
+        assert_eq!(rows, stored);
+    }
+}
+
+#[test]
+fn spiral_walk() {
+    let center = Hex::new(-3, 8);
+    assert_eq!(center.spiral_walk(0).collect::<Vec<_>>(), vec![center]);
+    for radius in 1..10 {
+        let path: Vec<_> = center.spiral_walk(radius).collect();
+        assert_eq!(path[0], center);
+        for pair in path.windows(2) {
+            assert_eq!(pair[0].unsigned_distance_to(pair[1]), 1);
+        }
+        for coord in center.range(radius) {
+            assert!(path.contains(&coord));
+        }
+        assert!(path.iter().all(|h| h.is_in_range(center, radius)));
+    }
+}
+
+#[test]
+fn distance_ordering() {
+    let center = Hex::new(-2, 4);
+    let mut coords: Vec<_> = Hex::ZERO.range(6).collect();
+    coords.sort_by(Hex::cmp_by_distance(center));
+    assert_eq!(coords[0], center);
+    for pair in coords.windows(2) {
+        assert!(pair[0].unsigned_distance_to(center) <= pair[1].unsigned_distance_to(center));
+    }
+}
+
 #[test]
 fn ring() {
     let point = Hex::ZERO;
@@ -433,6 +650,37 @@ fn ring() {
     }
 }
 
+#[test]
+fn rings_with_radius() {
+    let center = hex(-4, 2);
+    let rings: Vec<_> = center.rings_with_radius(0..=6).collect();
+    assert_eq!(rings.len(), 7);
+    for (i, (radius, ring)) in rings.into_iter().enumerate() {
+        assert_eq!(radius as usize, i);
+        assert!(ring.iter().all(|h| h.is_on_ring(center, radius)));
+        assert_eq!(ring, center.ring(radius).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn ring_membership() {
+    let center = Hex::new(3, -7);
+    for h in center.range(6) {
+        let on_ring = (0..=6).filter(|r| h.is_on_ring(center, *r)).count();
+        assert_eq!(on_ring, 1);
+        for radius in 0..=6 {
+            assert_eq!(
+                h.is_on_ring(center, radius),
+                center.ring(radius).any(|r| r == h)
+            );
+            assert_eq!(
+                h.is_in_range(center, radius),
+                center.range(radius).any(|r| r == h)
+            );
+        }
+    }
+}
+
 #[test]
 #[allow(clippy::cast_possible_truncation)]
 fn cached_rings() {
@@ -482,6 +730,55 @@ fn custom_ring() {
     }
 }
 
+#[test]
+#[allow(clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+fn ring_ordering() {
+    let center = Hex::new(-2, 5);
+    for start in EdgeDirection::ALL_DIRECTIONS {
+        // Radius 1 rings follow the edge ordering
+        let ring: Vec<_> = center.custom_ring(1, start, false).collect();
+        let cw_ring: Vec<_> = center.custom_ring(1, start, true).collect();
+        for i in 0..6 {
+            assert_eq!(ring[i as usize], center + (start + i));
+            assert_eq!(cw_ring[i as usize], center + (start - i));
+        }
+        // Larger rings go through each ring corner
+        for radius in 2..6 {
+            let ring: Vec<_> = center.custom_ring(radius, start, false).collect();
+            let cw_ring: Vec<_> = center.custom_ring(radius, start, true).collect();
+            for i in 0..6 {
+                let idx = (i as u32 * radius) as usize;
+                assert_eq!(ring[idx], center + (start + i) * radius as i32);
+                assert_eq!(cw_ring[idx], center + (start - i) * radius as i32);
+            }
+        }
+    }
+    assert_eq!(
+        Hex::ZERO.ring(1).collect::<Vec<_>>(),
+        EdgeDirection::ALL_DIRECTIONS.map(Hex::from).to_vec()
+    );
+}
+
+#[test]
+fn ring_winding() {
+    let center = Hex::new(4, -1);
+    for start in EdgeDirection::ALL_DIRECTIONS {
+        for radius in 1..6 {
+            let ring: Vec<_> = center.custom_ring(radius, start, false).collect();
+            let mut cw_ring: Vec<_> = center.custom_ring(radius, start, true).collect();
+            // Consecutive coordinates, including the last and first ones, are
+            // neighbors
+            for (a, b) in ring.iter().zip(ring.iter().cycle().skip(1)) {
+                assert_eq!(a.unsigned_distance_to(*b), 1);
+            }
+            // Both windings go through the same coordinates in reverse order
+            assert_eq!(cw_ring[0], ring[0]);
+            cw_ring[1..].reverse();
+            assert_eq!(cw_ring, ring);
+        }
+    }
+}
+
 #[test]
 fn ring_edge() {
     let point = Hex::new(-189, 35);
@@ -526,6 +823,29 @@ fn spiral_range() {
     }
 }
 
+#[test]
+#[allow(clippy::cast_possible_wrap)]
+fn custom_spiral_range() {
+    let center = hex(-3, 8);
+    for start in EdgeDirection::ALL_DIRECTIONS {
+        for clockwise in [true, false] {
+            let spiral: Vec<_> = center
+                .custom_spiral_range(0..=8, start, clockwise)
+                .collect();
+            assert_eq!(spiral.len(), Hex::range_count(8) as usize);
+            let mut rest = spiral.as_slice();
+            for radius in 0..=8 {
+                let (ring, next) = rest.split_at(Hex::ring_count(radius));
+                assert_eq!(ring[0], center + start * radius as i32);
+                let expected: Vec<_> = center.custom_ring(radius, start, clockwise).collect();
+                assert_eq!(ring, expected.as_slice());
+                rest = next;
+            }
+            assert!(rest.is_empty());
+        }
+    }
+}
+
 #[test]
 fn resolutions() {
     for res_radius in 0..=20 {
@@ -567,3 +887,74 @@ fn u64_conversion() {
         }
     }
 }
+
+#[test]
+fn wrap_in_parallelogram() {
+    let size = Hex::new(5, 3);
+    for coord in Hex::ZERO.range(20) {
+        let wrapped = coord.wrap_in_parallelogram(size);
+        assert!((0..size.x).contains(&wrapped.x));
+        assert!((0..size.y).contains(&wrapped.y));
+        // Translating by a full tile doesn't change the result
+        assert_eq!(
+            (coord + size * hex(2, -3)).wrap_in_parallelogram(size),
+            wrapped
+        );
+    }
+    for coord in crate::shapes::parallelogram(Hex::ZERO, size - 1) {
+        assert_eq!(coord.wrap_in_parallelogram(size), coord);
+    }
+}
+
+#[test]
+fn axis_access() {
+    for coord in Hex::ZERO.range(10) {
+        let cubic = coord.to_cubic_array();
+        for (i, v) in cubic.into_iter().enumerate() {
+            assert_eq!(coord.axis(i), v);
+        }
+        assert_eq!((0..3).map(|i| coord.axis(i)).sum::<i32>(), 0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn axis_out_of_range() {
+    let _ = Hex::ONE.axis(3);
+}
+
+#[test]
+fn string_conversion() {
+    for x in [-1234, -10, -1, 0, 1, 7, 2048] {
+        for y in [-321, -5, 0, 3, 99_999] {
+            let coord = Hex::new(x, y);
+            let s = coord.to_string();
+            assert_eq!(s, format!("{x},{y}"));
+            assert_eq!(s.parse::<Hex>(), Ok(coord));
+        }
+    }
+    assert_eq!("".parse::<Hex>(), Err(ParseHexError::InvalidFormat));
+    assert_eq!("12".parse::<Hex>(), Err(ParseHexError::InvalidFormat));
+    assert_eq!("1,2,3".parse::<Hex>(), Err(ParseHexError::InvalidFormat));
+    assert!(matches!(
+        "a,2".parse::<Hex>(),
+        Err(ParseHexError::InvalidCoordinate(_))
+    ));
+    assert!(matches!(
+        "1,".parse::<Hex>(),
+        Err(ParseHexError::InvalidCoordinate(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytemuck_cast() {
+    let coords: Vec<Hex> = Hex::ZERO.range(3).collect();
+    let bytes: &[u8] = bytemuck::cast_slice(&coords);
+    assert_eq!(bytes.len(), coords.len() * 8);
+    let ints: &[i32] = bytemuck::cast_slice(&coords);
+    assert_eq!(ints.len(), coords.len() * 2);
+    assert_eq!([ints[2], ints[3]], coords[1].to_array());
+    assert_eq!(bytemuck::cast_slice::<i32, Hex>(ints), coords.as_slice());
+    assert_eq!(<Hex as bytemuck::Zeroable>::zeroed(), Hex::ZERO);
+}
@@ -1,5 +1,5 @@
 use crate::{orientation::SQRT_3, EdgeDirection, Hex, HexOrientation, VertexDirection};
-use glam::Vec2;
+use glam::{Mat2, Vec2, Vec3};
 
 /// Hexagonal layout. This type is the bridge between your *world*/*pixel*
 /// coordinate system and the hexagonal coordinate system.
@@ -23,6 +23,8 @@ use glam::Vec2;
 ///     origin: Vec2::new(1.0, 2.0),
 ///     // We define the world space scale of the hexagons
 ///     scale: Vec2::new(1.0, 1.0),
+///     // We don't rotate the grid
+///     rotation: 0.0,
 /// };
 /// // You can now find the world positon (center) of any given hexagon
 /// let world_pos = layout.hex_to_world_pos(Hex::ZERO);
@@ -46,6 +48,22 @@ use glam::Vec2;
 /// layout.invert_y();
 /// ```
 ///
+/// ## Rotated grids
+///
+/// The whole grid can be rotated by an arbitrary angle, for example for
+/// isometric boards, using `HexLayout::with_rotation`. Every conversion and
+/// corner computation accounts for the rotation:
+///
+/// ```rust
+/// # use hexx::*;
+///
+/// let layout = HexLayout::flat()
+///     .with_rotation(0.3)
+///     .with_scale(Vec2::new(2.0, 1.0));
+/// let pos = layout.hex_to_world_pos(hex(2, -1));
+/// assert_eq!(layout.world_pos_to_hex(pos), hex(2, -1));
+/// ```
+///
 /// ## Working with Sprites
 ///
 /// If you intend to use the hexagonal grid to place images/sprites you may use
@@ -66,6 +84,13 @@ pub struct HexLayout {
     /// The size of individual hexagons in world/pixel space. The scale can be
     /// irregular or negative
     pub scale: Vec2,
+    /// Counter clockwise rotation of the grid in radians, usually `0.0`.
+    ///
+    /// The rotation is applied on top of the [`orientation`](Self::orientation)
+    /// and before the [`scale`](Self::scale). Defaults to `0.0` when missing
+    /// from serialized layouts.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rotation: f32,
 }
 
 impl HexLayout {
@@ -80,15 +105,15 @@ impl HexLayout {
     }
 
     /// Transforms a local hex space vector to world space
-    /// by applying the layout `scale` but NOT the origin
+    /// by applying the layout `rotation` and `scale` but NOT the origin
     #[must_use]
     #[inline]
     pub fn transform_vector(&self, vector: Vec2) -> Vec2 {
-        vector * self.scale
+        self.rotate(vector, self.rotation) * self.scale
     }
 
     /// Transforms a local hex point to world space
-    /// by applying the layout `scale` and `origin`
+    /// by applying the layout `rotation`, `scale` and `origin`
     #[must_use]
     #[inline]
     pub fn transform_point(&self, point: Vec2) -> Vec2 {
@@ -96,20 +121,41 @@ impl HexLayout {
     }
 
     /// Transforms a world space vector to local hex space
-    /// by applying the layout `scale` but NOT the origin
+    /// by applying the layout `scale` and `rotation` but NOT the origin
     #[must_use]
     #[inline]
     pub fn inverse_transform_vector(&self, vector: Vec2) -> Vec2 {
-        vector / self.scale
+        self.rotate(vector / self.scale, -self.rotation)
     }
 
     /// Transforms a world pace point to local hex space
-    /// by applying the layout `scale` and `origin`
+    /// by applying the layout `origin`, `scale` and `rotation`
     #[must_use]
     #[inline]
     pub fn inverse_transform_point(&self, point: Vec2) -> Vec2 {
         self.inverse_transform_vector(point - self.origin)
     }
+
+    /// Returns `true` if exactly one of the layout axis is inverted.
+    ///
+    /// Such a layout has a negative *handedness*: hexagon corners are listed
+    /// in the reverse winding order, which mesh builders account for.
+    #[must_use]
+    #[inline]
+    pub fn is_mirrored(&self) -> bool {
+        self.scale.x * self.scale.y < 0.0
+    }
+
+    /// Rotates `vector` by `angle` radians, skipping the trigonometry for
+    /// non rotated layouts
+    #[inline]
+    fn rotate(&self, vector: Vec2, angle: f32) -> Vec2 {
+        if self.rotation == 0.0 {
+            vector
+        } else {
+            Vec2::from_angle(angle).rotate(vector)
+        }
+    }
 }
 
 impl HexLayout {
@@ -124,11 +170,26 @@ impl HexLayout {
     #[inline]
     /// Computes hexagonal coordinates `hex` into world/pixel coordinates but
     /// ignoring [`HexLayout::origin`]
-    pub(crate) fn hex_to_center_aligned_world_pos(&self, hex: Hex) -> Vec2 {
+    ///
+    /// This is useful for custom mesh generation, as the builders compute
+    /// meshes around `(0.0, 0.0)` before offsetting them.
+    pub fn hex_to_center_aligned_world_pos(&self, hex: Hex) -> Vec2 {
         let p = self.orientation.forward(hex.as_vec2());
         self.transform_vector(p)
     }
 
+    #[must_use]
+    #[inline]
+    /// Computes the euclidian world/pixel distance between the centers of the
+    /// `a` and `b` hexagonal coordinates.
+    ///
+    /// Unlike [`Hex::unsigned_distance_to`] which counts hexagonal steps, this
+    /// distance is continuous in world space and accounts for the layout
+    /// `scale`.
+    pub fn world_distance(&self, a: Hex, b: Hex) -> f32 {
+        self.hex_to_center_aligned_world_pos(a - b).length()
+    }
+
     #[must_use]
     #[inline]
     /// Computes fractional hexagonal coordinates `hex` into world/pixel
@@ -141,9 +202,150 @@ impl HexLayout {
     #[must_use]
     #[inline]
     /// Computes world/pixel coordinates `pos` into hexagonal coordinates
+    ///
+    /// This rounds the output of [`Self::world_pos_to_fractional_hex`] with
+    /// [`Hex::round`]
     pub fn world_pos_to_hex(&self, pos: Vec2) -> Hex {
-        let p = self.world_pos_to_fract_hex(pos).to_array();
-        Hex::round(p)
+        let [x, y, _] = self.world_pos_to_fractional_hex(pos);
+        Hex::round([x, y])
+    }
+
+    #[must_use]
+    #[inline]
+    /// Computes world/pixel coordinates `pos` into un-rounded *cubic*
+    /// hexagonal coordinates `[x, y, z]`, with `x + y + z = 0`.
+    ///
+    /// Unlike [`Self::world_pos_to_hex`], no rounding is applied, which is
+    /// useful for smooth interpolations or to measure how far `pos` is from a
+    /// hexagon center.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let a = layout.hex_to_world_pos(hex(1, 0));
+    /// let b = layout.hex_to_world_pos(hex(2, 0));
+    /// // Halfway between two hexagon centers
+    /// let [x, y, z] = layout.world_pos_to_fractional_hex(a.lerp(b, 0.5));
+    /// assert!((x - 1.5).abs() < 1e-5);
+    /// assert!(y.abs() < 1e-5);
+    /// assert!((z + 1.5).abs() < 1e-5);
+    /// ```
+    pub fn world_pos_to_fractional_hex(&self, pos: Vec2) -> [f32; 3] {
+        let [x, y] = self.world_pos_to_fract_hex(pos).to_array();
+        [x, y, -x - y]
+    }
+
+    #[must_use]
+    #[inline]
+    /// Retrieves the hexagonal coordinates in the `to` layout matching the
+    /// position of `hex` in this layout.
+    ///
+    /// The `hex` center is converted to world space and back to the `to`
+    /// layout, so the layouts may have different orientations, sizes, origins
+    /// or inverted axis. Useful for overlay grids.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::flat().with_hex_size(2.0);
+    /// let overlay = HexLayout::flat().with_hex_size(1.0);
+    /// assert_eq!(layout.convert_hex(hex(1, 2), &overlay), hex(2, 4));
+    /// ```
+    pub fn convert_hex(&self, hex: Hex, to: &Self) -> Hex {
+        to.world_pos_to_hex(self.hex_to_world_pos(hex))
+    }
+
+    #[must_use]
+    /// Computes every world/pixel coordinates in `positions` into hexagonal
+    /// coordinates.
+    ///
+    /// Produces the same output as calling [`Self::world_pos_to_hex`] on each
+    /// position, but the orientation matrix and layout transform are
+    /// retrieved once for the whole batch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::pointy().with_hex_size(2.0);
+    /// let positions = [Vec2::ZERO, Vec2::new(10.0, -3.5)];
+    /// let coords = layout.world_positions_to_hexes(&positions);
+    /// assert_eq!(coords[1], layout.world_pos_to_hex(positions[1]));
+    /// ```
+    pub fn world_positions_to_hexes(&self, positions: &[Vec2]) -> Vec<Hex> {
+        let matrix = self.orientation.inverse_matrix * Mat2::from_angle(-self.rotation);
+        let (origin, scale) = (self.origin, self.scale);
+        positions
+            .iter()
+            .map(|&pos| Hex::round(matrix.mul_vec2((pos - origin) / scale).to_array()))
+            .collect()
+    }
+
+    #[must_use]
+    /// Computes every hexagonal coordinates in `hexes` into world/pixel
+    /// coordinates.
+    ///
+    /// Produces the same output as calling [`Self::hex_to_world_pos`] on each
+    /// coordinate, but the orientation matrix and layout transform are
+    /// retrieved once for the whole batch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::pointy().with_hex_size(2.0);
+    /// let coords = [hex(0, 0), hex(3, -1)];
+    /// let positions = layout.hexes_to_world_positions(&coords);
+    /// assert_eq!(positions[1], layout.hex_to_world_pos(coords[1]));
+    /// ```
+    pub fn hexes_to_world_positions(&self, hexes: &[Hex]) -> Vec<Vec2> {
+        let matrix = Mat2::from_angle(self.rotation) * self.orientation.forward_matrix;
+        let (origin, scale) = (self.origin, self.scale);
+        hexes
+            .iter()
+            .map(|hex| matrix.mul_vec2(hex.as_vec2()) * scale + origin)
+            .collect()
+    }
+
+    #[must_use]
+    /// Retrieves the hexagonal coordinates hit by a 3D ray on the horizontal
+    /// plane at `plane_y`, useful for 3D picking.
+    ///
+    /// The 2D world positions are mapped on the `X` and `Z` axis, as in mesh
+    /// generation.
+    ///
+    /// Returns `None` if the ray is parallel to the plane or pointing away
+    /// from it.
+    ///
+    /// # Arguments
+    ///
+    /// * `origin` - the ray origin
+    /// * `direction` - the ray direction, doesn't need to be normalized
+    /// * `plane_y` - the height of the ground plane
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let camera = Vec3::new(0.0, 10.0, 0.0);
+    /// let hit = layout.hex_from_ray(camera, Vec3::NEG_Y, 0.0);
+    /// assert_eq!(hit, Some(Hex::ZERO));
+    /// assert_eq!(layout.hex_from_ray(camera, Vec3::Y, 0.0), None);
+    /// ```
+    pub fn hex_from_ray(&self, origin: Vec3, direction: Vec3, plane_y: f32) -> Option<Hex> {
+        if direction.y.abs() <= f32::EPSILON {
+            return None;
+        }
+        let t = (plane_y - origin.y) / direction.y;
+        if t < 0.0 {
+            return None;
+        }
+        let hit = origin + direction * t;
+        Some(self.world_pos_to_hex(Vec2::new(hit.x, hit.z)))
     }
 
     #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
@@ -155,6 +357,184 @@ impl HexLayout {
         self.orientation.inverse(point)
     }
 
+    /// Converts `path` into world/pixel positions evenly spaced by `spacing`
+    /// along the polyline joining the hexagon centers.
+    ///
+    /// The first point is the center of the first hexagon and the last
+    /// point is always the center of the last hexagon, even if closer than
+    /// `spacing` from the previous point. This is useful for constant speed
+    /// movement animations.
+    ///
+    /// If `spacing` is not strictly positive the hexagon centers are returned
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let path: Vec<Hex> = hex(0, 0).line_to(hex(5, -2)).collect();
+    /// let points = layout.resample_path(&path, 0.25);
+    /// assert_eq!(points[0], layout.hex_to_world_pos(hex(0, 0)));
+    /// assert_eq!(points.last(), Some(&layout.hex_to_world_pos(hex(5, -2))));
+    /// ```
+    #[must_use]
+    #[allow(clippy::while_float)]
+    pub fn resample_path(&self, path: &[Hex], spacing: f32) -> Vec<Vec2> {
+        let centers: Vec<Vec2> = path.iter().map(|h| self.hex_to_world_pos(*h)).collect();
+        let (Some(&first), Some(&last)) = (centers.first(), centers.last()) else {
+            return centers;
+        };
+        if spacing <= 0.0 || spacing.is_nan() {
+            return centers;
+        }
+        let mut points = vec![first];
+        // Distance along the current segment of the next point
+        let mut next = spacing;
+        for segment in centers.windows(2) {
+            let [a, b] = [segment[0], segment[1]];
+            let len = a.distance(b);
+            while next <= len {
+                points.push(a.lerp(b, next / len));
+                next += spacing;
+            }
+            next -= len;
+        }
+        if points
+            .last()
+            .is_some_and(|p| !p.abs_diff_eq(last, f32::EPSILON))
+        {
+            points.push(last);
+        }
+        points
+    }
+
+    /// Returns all hexagonal coordinates whose area overlaps the world/pixel
+    /// rectangle defined by its `min` and `max` corners.
+    ///
+    /// # Inclusion criterion
+    ///
+    /// A coordinate is included if its hexagon *bounding rect* (see
+    /// [`Self::rect_size`]) intersects the rectangle, borders excluded. This is
+    /// more permissive than a true polygon overlap: coordinates only touching
+    /// the rectangle with their bounding rect corners may be included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// // A rect smaller than a single hexagon
+    /// let center = layout.hex_to_world_pos(hex(2, 3));
+    /// let coords: Vec<Hex> = layout
+    ///     .hexes_overlapping_rect(center - 0.1, center + 0.1)
+    ///     .collect();
+    /// assert_eq!(coords, vec![hex(2, 3)]);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn hexes_overlapping_rect(&self, min: Vec2, max: Vec2) -> impl Iterator<Item = Hex> + '_ {
+        let (min, max) = (min.min(max), min.max(max));
+        let half_extents = (max - min) / 2.0 + self.rect_size().abs() / 2.0;
+        let center = (min + max) / 2.0;
+        // Every overlapping hexagon has its center in the expanded rect
+        let corners = [
+            center - half_extents,
+            center + half_extents,
+            center + Vec2::new(half_extents.x, -half_extents.y),
+            center + Vec2::new(-half_extents.x, half_extents.y),
+        ]
+        .map(|p| self.world_pos_to_fract_hex(p));
+        let [hex_min, hex_max] = [
+            corners.into_iter().reduce(Vec2::min).unwrap_or_default(),
+            corners.into_iter().reduce(Vec2::max).unwrap_or_default(),
+        ];
+        let [min_x, min_y] = hex_min.floor().as_ivec2().to_array();
+        let [max_x, max_y] = hex_max.ceil().as_ivec2().to_array();
+        (min_x..=max_x)
+            .flat_map(move |x| (min_y..=max_y).map(move |y| Hex::new(x, y)))
+            .filter(move |h| {
+                let delta = (self.hex_to_world_pos(*h) - center).abs();
+                delta.cmplt(half_extents).all()
+            })
+    }
+
+    /// Computes the world/pixel bounding rectangle of the given `hexes`,
+    /// covering all of their corners, as `(min, max)`.
+    ///
+    /// Returns `None` if `hexes` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::flat();
+    /// let (min, max) = layout.bounds_of([hex(0, 0)]).unwrap();
+    /// assert!(min.abs_diff_eq(-layout.rect_size() / 2.0, 1e-5));
+    /// assert!(max.abs_diff_eq(layout.rect_size() / 2.0, 1e-5));
+    /// assert!(layout.bounds_of([]).is_none());
+    /// ```
+    #[must_use]
+    pub fn bounds_of(&self, hexes: impl IntoIterator<Item = Hex>) -> Option<(Vec2, Vec2)> {
+        let offsets = self.corner_offsets();
+        let [offset_min, offset_max] = [
+            offsets.into_iter().fold(Vec2::MAX, Vec2::min),
+            offsets.into_iter().fold(Vec2::MIN, Vec2::max),
+        ];
+        hexes
+            .into_iter()
+            .map(|h| self.hex_to_world_pos(h))
+            .map(|center| (center + offset_min, center + offset_max))
+            .reduce(|(min, max), (a, b)| (min.min(a), max.max(b)))
+    }
+
+    /// Expands the world/pixel rectangle defined by its `min` and `max` corners
+    /// outward to the hexagon boundaries, returning the new `(min, max)`
+    /// corners.
+    ///
+    /// The returned rectangle is the bounding rect of the corners of every
+    /// hexagon returned by [`Self::hexes_overlapping_rect`], so it fully
+    /// contains these hexagons and the original rectangle.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let (min, max) = (Vec2::new(-3.0, 1.0), Vec2::new(4.5, 6.0));
+    /// let (snapped_min, snapped_max) = layout.snap_rect_to_hexes(min, max);
+    /// assert!(snapped_min.cmple(min).all());
+    /// assert!(snapped_max.cmpge(max).all());
+    /// ```
+    #[must_use]
+    pub fn snap_rect_to_hexes(&self, min: Vec2, max: Vec2) -> (Vec2, Vec2) {
+        let (min, max) = (min.min(max), min.max(max));
+        let offsets = self.corner_offsets();
+        self.hexes_overlapping_rect(min, max)
+            .flat_map(|h| {
+                let center = self.hex_to_world_pos(h);
+                offsets.map(|o| o + center)
+            })
+            .fold((min, max), |(min, max), p| (min.min(p), max.max(p)))
+    }
+
+    #[must_use]
+    /// Retrieves the 6 neighbors of `hex` paired with their world/pixel
+    /// positions, in the same order as [`Hex::all_neighbors`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let coord = hex(2, -1);
+    /// for (neighbor, pos) in layout.neighbors_with_world_pos(coord) {
+    ///     assert_eq!(layout.world_pos_to_hex(pos), neighbor);
+    /// }
+    /// ```
+    pub fn neighbors_with_world_pos(&self, hex: Hex) -> [(Hex, Vec2); 6] {
+        let center = self.hex_to_world_pos(hex);
+        Hex::NEIGHBORS_COORDS.map(|n| (hex + n, center + self.hex_to_center_aligned_world_pos(n)))
+    }
+
     #[must_use]
     /// Retrieves all 6 corner coordinates of the given hexagonal coordinates
     /// `hex`
@@ -163,6 +543,27 @@ impl HexLayout {
         self.center_aligned_hex_corners().map(|c| c + center)
     }
 
+    /// Retrieves all 6 corner coordinates of the given hexagonal coordinates
+    /// `hex`, rotated around the hexagon center by `extra_angle` radians.
+    ///
+    /// The hexagon center stays on the grid, which allows per-tile rotation
+    /// variations without disturbing the layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let corners = layout.hex_corners_rotated(hex(1, 2), 0.1);
+    /// ```
+    #[must_use]
+    pub fn hex_corners_rotated(&self, hex: Hex, extra_angle: f32) -> [Vec2; 6] {
+        let center = self.hex_to_world_pos(hex);
+        let rotation = Vec2::from_angle(extra_angle);
+        self.center_aligned_hex_corners()
+            .map(|c| rotation.rotate(c) + center)
+    }
+
     /// Retrieves all 6 edge corner pair coordinates of the given hexagonal
     /// coordinates `hex`
     #[must_use]
@@ -172,33 +573,164 @@ impl HexLayout {
             .map(|p| p.map(|c| c + center))
     }
 
+    /// Retrieves the two edge segments of the given hexagonal coordinates
+    /// `hex` meeting at the `corner`, in clockwise order.
+    ///
+    /// Each segment is ordered like [`Self::hex_edge_corners`], so `corner` is
+    /// the second point of the first segment and the first point of the
+    /// second segment. Useful to round hexagon corners or to place corner
+    /// decorations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let coord = hex(1, 2);
+    /// let corner = VertexDirection::FLAT_RIGHT;
+    /// let [[_, a], [b, _]] = layout.corner_edges(coord, corner);
+    /// let corner_pos = layout.hex_corners(coord)[corner.index() as usize];
+    /// assert_eq!(a, corner_pos);
+    /// assert_eq!(b, corner_pos);
+    /// ```
     #[must_use]
-    /// Retrieves all 6 edge corner pair coordinates of the given hexagonal
-    /// coordinates `hex` without offsetting at the origin
+    pub fn corner_edges(&self, hex: Hex, corner: VertexDirection) -> [[Vec2; 2]; 2] {
+        let edges = self.hex_edge_corners(hex);
+        corner
+            .edge_directions()
+            .map(|dir| edges[usize::from(dir.index())])
+    }
+
+    /// Retrieves the closed outline of the given hexagonal coordinates `hex`
+    /// in 3d space, as the 6 corners followed by the first corner again.
+    ///
+    /// The outline lies on the `XZ` plane at the given `height`, matching the
+    /// mesh builders, and is suited for *line strip* gizmos.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let outline = layout.hex_outline_3d(hex(1, 2), 5.0);
+    /// assert_eq!(outline[0], outline[6]);
+    /// ```
+    #[must_use]
+    pub fn hex_outline_3d(&self, hex: Hex, height: f32) -> [Vec3; 7] {
+        let corners = self.hex_corners(hex).map(|c| Vec3::new(c.x, height, c.y));
+        std::array::from_fn(|i| corners[i % 6])
+    }
+
+    #[must_use]
+    /// Retrieves all 6 corner coordinates of an hexagon centered around
+    /// `(0.0, 0.0)`, ignoring [`HexLayout::origin`]
+    ///
+    /// See [`Self::corner_offsets`]
     pub fn center_aligned_hex_corners(&self) -> [Vec2; 6] {
-        VertexDirection::ALL_DIRECTIONS.map(|dir| dir.world_unit_vector(self))
+        self.corner_offsets()
     }
 
     #[must_use]
-    /// Non offsetted hex edges
-    pub(crate) fn center_aligned_edge_corners(&self) -> [[Vec2; 2]; 6] {
-        EdgeDirection::ALL_DIRECTIONS
-            .map(|dir| dir.vertex_directions().map(|v| v.world_unit_vector(self)))
+    /// Retrieves the offsets of the 6 corners from any hexagon center, in
+    /// [`VertexDirection::ALL_DIRECTIONS`] order.
+    ///
+    /// The offsets are identical for every coordinate and are computed from
+    /// precomputed unit vectors, without any trigonometry unless the layout is
+    /// rotated. For large grids,
+    /// compute them once and add them to each hexagon center.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::pointy().with_scale(Vec2::splat(2.0));
+    /// let offsets = layout.corner_offsets();
+    /// for coord in Hex::ZERO.range(5) {
+    ///     let center = layout.hex_to_world_pos(coord);
+    ///     assert_eq!(layout.hex_corners(coord), offsets.map(|o| o + center));
+    /// }
+    /// ```
+    pub fn corner_offsets(&self) -> [Vec2; 6] {
+        self.unit_corners().map(|c| self.transform_vector(c))
+    }
+
+    /// Corner unit vectors of the layout orientation, in local hex space
+    const fn unit_corners(&self) -> [Vec2; 6] {
+        const HALF_SQRT_3: f32 = SQRT_3 / 2.0;
+        const FLAT_CORNERS: [Vec2; 6] = [
+            Vec2::new(1.0, 0.0),
+            Vec2::new(0.5, HALF_SQRT_3),
+            Vec2::new(-0.5, HALF_SQRT_3),
+            Vec2::new(-1.0, 0.0),
+            Vec2::new(-0.5, -HALF_SQRT_3),
+            Vec2::new(0.5, -HALF_SQRT_3),
+        ];
+        const POINTY_CORNERS: [Vec2; 6] = [
+            Vec2::new(HALF_SQRT_3, -0.5),
+            Vec2::new(HALF_SQRT_3, 0.5),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(-HALF_SQRT_3, 0.5),
+            Vec2::new(-HALF_SQRT_3, -0.5),
+            Vec2::new(0.0, -1.0),
+        ];
+        match self.orientation {
+            HexOrientation::Flat => FLAT_CORNERS,
+            HexOrientation::Pointy => POINTY_CORNERS,
+        }
+    }
+
+    /// Returns the normalized outward normal of the edge in the given
+    /// `direction`, perpendicular to the edge segment.
+    ///
+    /// The normal is identical for every coordinate, and differs from the
+    /// edge direction for irregular scales.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::flat().with_scale(Vec2::new(3.0, 1.0));
+    /// let dir = EdgeDirection::FLAT_TOP_RIGHT;
+    /// let [a, b] = layout.hex_edge_corners(Hex::ZERO)[dir.index() as usize];
+    /// let normal = layout.edge_normal(dir);
+    /// assert!(normal.dot(b - a).abs() < 1e-5);
+    /// ```
+    #[must_use]
+    pub fn edge_normal(&self, direction: EdgeDirection) -> Vec2 {
+        let [a, b] = direction
+            .vertex_directions()
+            .map(|v| v.world_unit_vector(self));
+        let normal = (b - a).perp().normalize();
+        // The edge middle point is outwards from the center
+        if normal.dot(a + b) < 0.0 {
+            -normal
+        } else {
+            normal
+        }
+    }
+
+    #[must_use]
+    /// Retrieves all 6 edge corner pair coordinates of an hexagon centered
+    /// around `(0.0, 0.0)`, ignoring [`HexLayout::origin`]
+    pub fn center_aligned_edge_corners(&self) -> [[Vec2; 2]; 6] {
+        let corners = self.corner_offsets();
+        EdgeDirection::ALL_DIRECTIONS.map(|dir| {
+            dir.vertex_directions()
+                .map(|v| corners[usize::from(v.index())])
+        })
     }
 
     #[inline]
     #[must_use]
     /// Returns the size of the bounding box/rect of an hexagon
-    /// This uses both the `hex_size` and `orientation` of the layout.
+    /// This uses the `scale`, `orientation` and `rotation` of the layout.
     pub fn rect_size(&self) -> Vec2 {
-        const FLAT_RECT: Vec2 = Vec2::new(2.0, SQRT_3);
-        const POINTY_RECT: Vec2 = Vec2::new(SQRT_3, 2.0);
-
-        self.scale
-            * match self.orientation {
-                HexOrientation::Pointy => POINTY_RECT,
-                HexOrientation::Flat => FLAT_RECT,
-            }
+        let half_size = self
+            .unit_corners()
+            .into_iter()
+            .map(|c| self.rotate(c, self.rotation).abs())
+            .fold(Vec2::ZERO, Vec2::max);
+        self.scale * half_size * 2.0
     }
 }
 
@@ -247,6 +779,7 @@ impl HexLayout {
             orientation,
             origin: Vec2::ZERO,
             scale: Vec2::ONE,
+            rotation: 0.0,
         }
     }
 
@@ -274,6 +807,17 @@ impl HexLayout {
         self
     }
 
+    #[must_use]
+    #[inline]
+    /// Specifies the counter clockwise `rotation` of the grid, in radians.
+    ///
+    /// Useful for grids which are neither *flat* nor *pointy*, like
+    /// isometric boards.
+    pub const fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
     #[must_use]
     #[inline]
     /// Specifies the world/pixel regular size of individual hexagons
@@ -288,15 +832,8 @@ impl HexLayout {
     /// the given `rect_size`. This is useful if you want hexagons
     /// to match a sprite size
     pub fn with_rect_size(self, rect_size: Vec2) -> Self {
-        const FLAT_RECT: Vec2 = Vec2::new(0.5, 1.0 / SQRT_3);
-        const POINTY_RECT: Vec2 = Vec2::new(1.0 / SQRT_3, 0.5);
-
-        let scale = rect_size
-            * match self.orientation {
-                HexOrientation::Pointy => POINTY_RECT,
-                HexOrientation::Flat => FLAT_RECT,
-            };
-        self.with_scale(scale)
+        let unit_rect = self.clone().with_scale(Vec2::ONE).rect_size();
+        self.with_scale(rect_size / unit_rect)
     }
 
     #[must_use]
@@ -310,6 +847,54 @@ impl HexLayout {
         self.scale = scale;
         self
     }
+
+    #[must_use]
+    #[inline]
+    /// Returns a copy of `self` with its [`scale`](Self::scale) multiplied by
+    /// `factor`, keeping the same orientation and origin.
+    ///
+    /// This is useful to keep multiple zoom levels of the same layout, for
+    /// example for level of detail rendering. To set an absolute scale
+    /// instead, use [`Self::with_zoom`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::pointy().with_hex_size(10.0);
+    /// let zoomed_out = layout.scaled(0.5);
+    /// assert_eq!(zoomed_out.scale, Vec2::splat(5.0));
+    /// ```
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            scale: self.scale * factor,
+            ..self.clone()
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    /// Returns a copy of `self` with its [`scale`](Self::scale) set to
+    /// `hex_size`, keeping the same orientation and origin.
+    ///
+    /// Unlike [`Self::with_scale`] the original layout is left untouched,
+    /// which is useful to share a layout between zoom levels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::pointy().with_hex_size(10.0);
+    /// let minimap = layout.with_zoom(Vec2::splat(2.0));
+    /// assert_eq!(minimap.scale, Vec2::splat(2.0));
+    /// assert_eq!(layout.scale, Vec2::splat(10.0));
+    /// ```
+    pub fn with_zoom(&self, hex_size: Vec2) -> Self {
+        Self {
+            scale: hex_size,
+            ..self.clone()
+        }
+    }
 }
 
 impl Default for HexLayout {
@@ -387,6 +972,372 @@ mod tests {
         );
     }
 
+    #[test]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn resample_path() {
+        let layout = HexLayout::pointy().with_hex_size(2.0);
+        let path: Vec<Hex> = Hex::ZERO.line_to(Hex::new(6, 0)).collect();
+        let spacing = 0.7;
+        let points = layout.resample_path(&path, spacing);
+        assert_eq!(points[0], layout.hex_to_world_pos(path[0]));
+        assert_eq!(points.last(), Some(&layout.hex_to_world_pos(path[6])));
+        for pair in points[..points.len() - 1].windows(2) {
+            assert_relative_eq!(pair[0].distance(pair[1]), spacing, epsilon = 0.0001);
+        }
+        let len = layout.hex_to_world_pos(path[6]).length();
+        assert_eq!(points.len(), (len / spacing).ceil() as usize + 1);
+        // Degenerate cases
+        assert!(layout.resample_path(&[], spacing).is_empty());
+        assert_eq!(layout.resample_path(&path, 0.0).len(), path.len());
+        assert_eq!(layout.resample_path(&path[..1], spacing).len(), 1);
+    }
+
+    #[test]
+    fn hexes_overlapping_rect() {
+        let rects = [
+            (Vec2::ZERO, Vec2::ZERO),
+            (Vec2::new(-0.1, -0.2), Vec2::new(0.3, 0.1)),
+            (Vec2::new(-10.0, 5.0), Vec2::new(13.0, 20.5)),
+            (Vec2::new(3.0, 4.0), Vec2::new(-7.5, -2.0)),
+        ];
+        for layout in [
+            HexLayout::flat().with_scale(Vec2::new(2.0, 1.5)),
+            HexLayout::pointy().with_scale(Vec2::new(-1.0, 3.0)),
+        ] {
+            let size = layout.rect_size().abs();
+            for (a, b) in rects {
+                let (min, max) = (a.min(b), a.max(b));
+                let mut expected: Vec<Hex> = Hex::ZERO
+                    .range(30)
+                    .filter(|h| {
+                        let pos = layout.hex_to_world_pos(*h);
+                        let [h_min, h_max] = [pos - size / 2.0, pos + size / 2.0];
+                        h_min.cmplt(max).all() && h_max.cmpgt(min).all()
+                    })
+                    .collect();
+                let mut coords: Vec<Hex> = layout.hexes_overlapping_rect(a, b).collect();
+                expected.sort_by_key(|h| (h.x, h.y));
+                coords.sort_by_key(|h| (h.x, h.y));
+                assert!(!coords.is_empty());
+                assert_eq!(coords, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn rotated_corners() {
+        let layout = HexLayout::pointy().with_scale(Vec2::new(2.0, 3.0));
+        let coord = Hex::new(3, -1);
+        let center = layout.hex_to_world_pos(coord);
+        let corners = layout.hex_corners(coord);
+        assert_eq!(layout.hex_corners_rotated(coord, 0.0), corners);
+        let angle = 0.3;
+        let rotated = layout.hex_corners_rotated(coord, angle);
+        for (corner, rotated) in corners.into_iter().zip(rotated) {
+            let [a, b] = [corner - center, rotated - center];
+            assert_relative_eq!(a.length(), b.length(), epsilon = 0.0001);
+            assert_relative_eq!(a.angle_to(b), angle, epsilon = 0.0001);
+        }
+        // Full turn
+        let rotated = layout.hex_corners_rotated(coord, std::f32::consts::TAU);
+        for (corner, rotated) in corners.into_iter().zip(rotated) {
+            assert!(corner.abs_diff_eq(rotated, 0.0001));
+        }
+    }
+
+    #[test]
+    fn rotated_layout() {
+        use crate::orientation::HexOrientationData;
+
+        for angle in [0.3, -1.2, 2.5] {
+            let layout = HexLayout::flat()
+                .with_rotation(angle)
+                .with_origin(Vec2::new(2.0, -1.0))
+                .with_scale(Vec2::new(1.5, -2.0));
+            let data = HexOrientationData::from_angle(angle);
+            let coords: Vec<Hex> = Hex::ZERO.range(5).collect();
+            let positions = layout.hexes_to_world_positions(&coords);
+            assert_eq!(layout.world_positions_to_hexes(&positions), coords);
+            for (hex, pos) in coords.iter().zip(positions) {
+                let expected = data.forward(hex.as_vec2()) * layout.scale + layout.origin;
+                assert!(layout.hex_to_world_pos(*hex).abs_diff_eq(expected, 0.0001));
+                assert!(pos.abs_diff_eq(expected, 0.0001));
+                assert_eq!(layout.world_pos_to_hex(pos), *hex);
+            }
+            // Corners are rotated around the center
+            let unrotated = layout.clone().with_rotation(0.0);
+            for (corner, base) in layout
+                .corner_offsets()
+                .into_iter()
+                .zip(unrotated.corner_offsets())
+            {
+                let expected = Vec2::from_angle(angle).rotate(base / layout.scale) * layout.scale;
+                assert!(corner.abs_diff_eq(expected, 0.0001));
+            }
+            // Bounding rect covers the rotated corners
+            let offsets = layout.corner_offsets();
+            let max = offsets
+                .into_iter()
+                .map(Vec2::abs)
+                .fold(Vec2::ZERO, Vec2::max);
+            assert!((layout.rect_size().abs() / 2.0).abs_diff_eq(max, 0.0001));
+        }
+        // A pointy layout is a flat layout rotated by -30 degrees
+        let pointy = HexLayout::pointy().with_scale(Vec2::new(2.0, 3.0));
+        let rotated = HexLayout::flat()
+            .with_rotation(-std::f32::consts::FRAC_PI_6)
+            .with_scale(Vec2::new(2.0, 3.0));
+        for hex in Hex::ZERO.range(5) {
+            assert!(pointy
+                .hex_to_world_pos(hex)
+                .abs_diff_eq(rotated.hex_to_world_pos(hex), 0.0001));
+        }
+        assert!(pointy.rect_size().abs_diff_eq(rotated.rect_size(), 0.0001));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialize_without_rotation() {
+        let json = r#"{"orientation":"Pointy","origin":[1.0,2.0],"scale":[3.0,4.0]}"#;
+        let layout: HexLayout = serde_json::from_str(json).expect("Failed to deserialize");
+        assert_eq!(layout.orientation, HexOrientation::Pointy);
+        assert_eq!(layout.origin, Vec2::new(1.0, 2.0));
+        assert_eq!(layout.scale, Vec2::new(3.0, 4.0));
+        assert!(layout.rotation.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn scaled_layout() {
+        let layout = HexLayout::flat()
+            .with_origin(Vec2::new(5.0, -2.0))
+            .with_scale(Vec2::new(2.0, -3.0));
+        let scaled = layout.scaled(2.5);
+        assert_eq!(scaled.origin, layout.origin);
+        assert_eq!(scaled.orientation, layout.orientation);
+        let zoomed = layout.with_zoom(Vec2::new(4.0, -1.0));
+        assert_eq!(zoomed.scale, Vec2::new(4.0, -1.0));
+        assert_eq!(zoomed.origin, layout.origin);
+        assert_eq!(layout.scale, Vec2::new(2.0, -3.0));
+        for hex in Hex::ZERO.range(3) {
+            let pos = layout.hex_to_center_aligned_world_pos(hex) * 2.5;
+            assert!(scaled
+                .hex_to_center_aligned_world_pos(hex)
+                .abs_diff_eq(pos, 0.0001));
+        }
+    }
+
+    #[test]
+    fn neighbors_world_pos() {
+        let layout = HexLayout::pointy()
+            .with_origin(Vec2::new(-3.0, 2.0))
+            .with_scale(Vec2::new(1.5, -2.0));
+        let coord = Hex::new(4, -7);
+        let neighbors = layout.neighbors_with_world_pos(coord);
+        for ((neighbor, pos), expected) in neighbors.into_iter().zip(coord.all_neighbors()) {
+            assert_eq!(neighbor, expected);
+            assert!(pos.abs_diff_eq(layout.hex_to_world_pos(expected), 0.0001));
+        }
+    }
+
+    #[test]
+    fn ray_picking() {
+        let layout = HexLayout::pointy()
+            .with_origin(Vec2::new(2.0, -1.0))
+            .with_hex_size(1.5);
+        let camera = Vec3::new(-4.0, 12.0, 7.0);
+        for hex in Hex::ZERO.range(5) {
+            let pos = layout.hex_to_world_pos(hex);
+            for plane_y in [-2.0, 0.0, 3.5] {
+                let target = Vec3::new(pos.x, plane_y, pos.y);
+                assert_eq!(
+                    layout.hex_from_ray(camera, target - camera, plane_y),
+                    Some(hex)
+                );
+                // Pointing away from the plane
+                assert_eq!(layout.hex_from_ray(camera, camera - target, plane_y), None);
+            }
+        }
+        assert_eq!(layout.hex_from_ray(camera, Vec3::X, 0.0), None);
+    }
+
+    #[test]
+    fn world_distance() {
+        let layout = HexLayout::pointy()
+            .with_origin(Vec2::new(3.0, -7.0))
+            .with_scale(Vec2::new(2.0, -0.5));
+        for a in Hex::ZERO.range(3) {
+            for b in Hex::new(2, -5).range(3) {
+                let expected = layout
+                    .hex_to_world_pos(a)
+                    .distance(layout.hex_to_world_pos(b));
+                assert!((layout.world_distance(a, b) - expected).abs() < 0.0001);
+            }
+        }
+        let layout = HexLayout::flat().with_hex_size(2.0);
+        let dist = 2.0 * 3.0_f32.sqrt();
+        for neighbor in Hex::ZERO.all_neighbors() {
+            assert!((layout.world_distance(Hex::ZERO, neighbor) - dist).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn center_aligned() {
+        for layout in [
+            HexLayout::flat().with_origin(Vec2::new(12.0, -3.5)),
+            HexLayout::pointy()
+                .with_origin(Vec2::new(-4.0, 8.0))
+                .with_scale(Vec2::new(2.0, -1.5)),
+        ] {
+            for hex in Hex::ZERO.range(5) {
+                let pos = layout.hex_to_world_pos(hex);
+                let aligned = layout.hex_to_center_aligned_world_pos(hex);
+                assert!(aligned.abs_diff_eq(pos - layout.origin, 0.0001));
+                let corners = layout.hex_corners(hex);
+                for (c, aligned) in corners.iter().zip(layout.center_aligned_hex_corners()) {
+                    assert!((*c - pos).abs_diff_eq(aligned, 0.0001));
+                }
+                let edges = layout.hex_edge_corners(hex);
+                for (e, aligned) in edges.iter().zip(layout.center_aligned_edge_corners()) {
+                    assert!((e[0] - pos).abs_diff_eq(aligned[0], 0.0001));
+                    assert!((e[1] - pos).abs_diff_eq(aligned[1], 0.0001));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn corner_offsets() {
+        for orientation in [HexOrientation::Flat, HexOrientation::Pointy] {
+            for scale in [Vec2::ONE, Vec2::new(2.0, -1.5), Vec2::new(-3.0, 0.5)] {
+                let layout = HexLayout::new(orientation).with_scale(scale);
+                let offsets = layout.corner_offsets();
+                for (dir, offset) in VertexDirection::ALL_DIRECTIONS.iter().zip(offsets) {
+                    assert!(dir.world_unit_vector(&layout).abs_diff_eq(offset, 0.0001));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn snap_rect_to_hexes() {
+        for layout in [
+            HexLayout::flat().with_origin(Vec2::new(1.5, -2.0)),
+            HexLayout::pointy().with_scale(Vec2::new(2.0, -1.0)),
+        ] {
+            let (min, max) = (Vec2::new(-7.3, -2.1), Vec2::new(5.2, 8.9));
+            let (snapped_min, snapped_max) = layout.snap_rect_to_hexes(max, min);
+            assert!(snapped_min.cmple(min).all());
+            assert!(snapped_max.cmpge(max).all());
+            // Bounds are aligned on hexagon corners
+            let corners: Vec<Vec2> = layout
+                .hexes_overlapping_rect(min, max)
+                .flat_map(|h| layout.hex_corners(h))
+                .collect();
+            for bound in [snapped_min, snapped_max] {
+                assert!(corners.iter().any(|c| (c.x - bound.x).abs() < 0.0001));
+                assert!(corners.iter().any(|c| (c.y - bound.y).abs() < 0.0001));
+            }
+        }
+    }
+
+    #[test]
+    fn corner_edges() {
+        for layout in [
+            HexLayout::flat(),
+            HexLayout::pointy().with_scale(Vec2::new(2.0, -1.0)),
+        ] {
+            let coord = Hex::new(3, -2);
+            let corners = layout.hex_corners(coord);
+            for corner in VertexDirection::ALL_DIRECTIONS {
+                let [[_, a], [b, _]] = layout.corner_edges(coord, corner);
+                assert_eq!(a, corners[corner.index() as usize]);
+                assert_eq!(b, corners[corner.index() as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn convert_hex() {
+        let layout = HexLayout::pointy()
+            .with_origin(Vec2::new(3.0, -1.0))
+            .with_hex_size(2.0);
+        let mut inverted = layout.clone();
+        inverted.invert_y();
+        let flat = HexLayout::flat().with_hex_size(0.5);
+        for coord in Hex::ZERO.range(10) {
+            assert_eq!(layout.convert_hex(coord, &layout), coord);
+            let converted = layout.convert_hex(coord, &inverted);
+            assert!(inverted
+                .hex_to_world_pos(converted)
+                .abs_diff_eq(layout.hex_to_world_pos(coord), 0.0001));
+            // Converted coordinates contain the source hexagon center
+            let converted = layout.convert_hex(coord, &flat);
+            let delta = flat.hex_to_world_pos(converted) - layout.hex_to_world_pos(coord);
+            assert!(delta.length() <= 0.5 + 0.0001);
+        }
+    }
+
+    #[test]
+    fn fractional_hex() {
+        for layout in [
+            HexLayout::flat().with_origin(Vec2::new(1.5, -2.0)),
+            HexLayout::pointy().with_scale(Vec2::new(2.0, -1.0)),
+        ] {
+            for hex in Hex::ZERO.range(10) {
+                let [x, y, z] = layout.world_pos_to_fractional_hex(layout.hex_to_world_pos(hex));
+                assert!(Vec2::new(x, y).abs_diff_eq(hex.as_vec2(), 0.0001));
+                assert_relative_eq!(z, -x - y);
+            }
+            for i in 0..100_u8 {
+                let pos = Vec2::new(f32::from(i) * 0.37, f32::from(i) * -0.91);
+                let [x, y, z] = layout.world_pos_to_fractional_hex(pos);
+                assert_relative_eq!(x + y + z, 0.0, epsilon = 0.0001);
+                assert_eq!(Hex::round([x, y]), layout.world_pos_to_hex(pos));
+            }
+        }
+    }
+
+    #[test]
+    fn batch_conversions() {
+        for layout in [
+            HexLayout::flat().with_origin(Vec2::new(1.5, -2.0)),
+            HexLayout::pointy().with_scale(Vec2::new(2.0, -1.0)),
+        ] {
+            let hexes: Vec<Hex> = Hex::ZERO.range(20).collect();
+            let positions = layout.hexes_to_world_positions(&hexes);
+            let expected: Vec<Vec2> = hexes.iter().map(|h| layout.hex_to_world_pos(*h)).collect();
+            assert_eq!(positions, expected);
+            // Arbitrary positions, not only hexagon centers
+            let positions: Vec<Vec2> = (0..10_000_u16)
+                .map(|i| Vec2::new(f32::from(i % 100) * 0.37, f32::from(i / 100) * -0.53))
+                .collect();
+            let coords = layout.world_positions_to_hexes(&positions);
+            let expected: Vec<Hex> = positions
+                .iter()
+                .map(|p| layout.world_pos_to_hex(*p))
+                .collect();
+            assert_eq!(coords, expected);
+        }
+    }
+
+    #[test]
+    fn bounds_of() {
+        for layout in [
+            HexLayout::flat().with_origin(Vec2::new(1.5, -2.0)),
+            HexLayout::pointy().with_scale(Vec2::new(2.0, -1.0)),
+        ] {
+            let hexes: Vec<Hex> = Hex::new(3, -1).range(3).collect();
+            let (min, max) = layout
+                .bounds_of(hexes.iter().copied())
+                .expect("Missing bounds");
+            let corners: Vec<Vec2> = hexes.iter().flat_map(|h| layout.hex_corners(*h)).collect();
+            let expected_min = corners.iter().copied().fold(Vec2::MAX, Vec2::min);
+            let expected_max = corners.iter().copied().fold(Vec2::MIN, Vec2::max);
+            assert!(min.abs_diff_eq(expected_min, 0.0001));
+            assert!(max.abs_diff_eq(expected_max, 0.0001));
+        }
+    }
+
     #[test]
     fn rect_size() {
         let sizes = [
@@ -29,6 +29,9 @@
 //!   most types
 //! * `packed`: Makes [`Hex`](crate::hex::Hex) `repr(C)`, useful to use it
 //!   accross the FII
+//! * `bytemuck`: Implements [bytemuck](https://docs.rs/bytemuck) `Pod` and
+//!   `Zeroable` for [`Hex`](crate::hex::Hex), useful to cast hex slices to GPU
+//!   buffers. Enables `packed`
 //! * `bevy_reflect`: Enables [Bevy Reflection](https://docs.rs/bevy_reflect/latest/bevy_reflect)
 //!   for most types. Useful for integration with [Bevy](https://bevyengine.org/)
 //! * `grid`: Enables support for Face/Vertex/Edge [grid handling](https://www.redblobgames.com/grids/parts/#hexagon-coordinates)
@@ -133,6 +136,11 @@
 //! assert!(bounds.is_in_bounds(wrapped_coord));
 //! ```
 //!
+//! For rectangular maps, [`HexRectBounds`](crate::bounds::HexRectBounds)
+//! provides the same features using [offset](https://www.redblobgames.com/grids/hexagons/#coordinates-offset)
+//! coordinates, and [`HexParallelogramBounds`](crate::bounds::HexParallelogramBounds)
+//! using axial coordinates.
+//!
 //! ## Resolutions and chunks
 //!
 //! [`Hex`](crate::hex::Hex) support multi-resolution coordinates.
@@ -198,13 +206,15 @@
 //!
 //! > Requires the `mesh` feature
 //!
-//! `hexx` provides 3 built-in procedural mesh construction utilies:
+//! `hexx` provides 4 built-in procedural mesh construction utilies:
 //! - [`PlaneMeshBuilder`](crate::mesh::plane_builder::PlaneMeshBuilder) for
 //!   hexagonal planes
 //! - [`ColumnMeshBuilder`](crate::mesh::column_builder::ColumnMeshBuilder)  for
 //!   hexagonal columns
 //! - [`HeightMapMeshBuilder`](crate::mesh::heightmap_builder::HeightMapMeshBuilder)
 //!   for hexagonal height maps
+//! - [`OutlineMeshBuilder`](crate::mesh::outline_builder::OutlineMeshBuilder)
+//!   for hexagonal outlines
 //!
 //! All those builders have a lot of customization options and will output a
 //! [`MeshInfo`](crate::mesh::MeshInfo) struct containing vertex positions,
@@ -237,6 +247,10 @@
 //!     .with_inserted_indices(Indices::U16(mesh_info.indices))
 //! }
 //! ```
+//!
+//! Meshes with more than `65535` vertices can use
+//! [`MeshInfo32`](crate::mesh::MeshInfo32) instead, inserting its indices as
+//! `Indices::U32`.
 #![forbid(unsafe_code)]
 #![warn(
     clippy::nursery,
@@ -268,12 +282,14 @@ pub mod layout;
 pub mod mesh;
 /// Hexagon oritentation module
 pub mod orientation;
+/// Rotation utils module
+pub mod rotation;
 /// Map shapes generation functions
 pub mod shapes;
 pub mod storage;
 
 #[doc(inline)]
-pub use bounds::HexBounds;
+pub use bounds::{HexBounds, HexParallelogramBounds, HexRectBounds};
 #[doc(inline)]
 pub use conversions::*;
 #[doc(inline)]
@@ -281,7 +297,7 @@ pub use direction::*;
 #[doc(hidden)]
 pub use glam::{IVec2, IVec3, Quat, Vec2, Vec3};
 #[doc(inline)]
-pub use hex::{hex, Hex, HexIterExt};
+pub use hex::{hex, Hex, HexIterExt, ParseHexError};
 #[doc(inline)]
 #[cfg(feature = "grid")]
 pub use hex::{GridEdge, GridVertex};
@@ -291,3 +307,5 @@ pub use layout::HexLayout;
 pub use mesh::*;
 #[doc(inline)]
 pub use orientation::HexOrientation;
+#[doc(inline)]
+pub use rotation::Rotatable;
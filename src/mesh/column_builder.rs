@@ -1,8 +1,9 @@
-use glam::{Quat, Vec3};
+use glam::{Mat2, Quat, Vec2, Vec3};
 
 use super::{face::Quad, MeshInfo, BASE_FACING};
 use crate::{
-    EdgeDirection, FaceOptions, Hex, HexLayout, InsetOptions, PlaneMeshBuilder, UVOptions,
+    DirectionMask, EdgeDirection, FaceOptions, Hex, HexLayout, InsetOptions, PlaneMeshBuilder,
+    Rect, UVOptions, VertexDirection,
 };
 
 /// Builder struct to customize hex column mesh generation.
@@ -66,6 +67,38 @@ pub struct ColumnMeshBuilder<'l> {
     pub sides_options: [Option<FaceOptions>; 6],
     /// If set to `true`, the mesh will ignore [`HexLayout::origin`]
     pub center_aligned: bool,
+    /// If set to `true`, normals of vertices sharing the same position will be
+    /// averaged, otherwise each face keeps its flat normal
+    pub smooth_normals: bool,
+    /// Optional wall thickness, making the column a hollow tube.
+    ///
+    /// If set, inner side walls are generated and the top and bottom faces
+    /// are replaced by *rims* connecting the outer and inner walls.
+    pub wall_thickness: Option<f32>,
+    /// If set to `true`, the mesh pivot will be the center of its bounding box
+    /// instead of the center of the bottom face
+    pub centered_pivot: bool,
+    /// Optional *triplanar* uvs scale. If set, the uvs are computed from the
+    /// final vertex positions, see [`MeshInfo::with_triplanar_uvs`]
+    pub triplanar_uvs: Option<f32>,
+    /// Optional custom top height of each corner, indexed by
+    /// [`VertexDirection`]. If set, `height` is ignored
+    pub corner_heights: Option<[f32; 6]>,
+    /// Optional bevel size, chamfering the edges between the top face and the
+    /// sides
+    pub bevel: Option<f32>,
+}
+
+/// Column mesh data split by face group, computed by
+/// [`ColumnMeshBuilder::build_parts`]
+#[derive(Debug, Clone, Default)]
+pub struct ColumnMeshParts {
+    /// The top face, or top rim for hollow columns. `None` if disabled
+    pub top: Option<MeshInfo>,
+    /// The side quads
+    pub sides: MeshInfo,
+    /// The bottom face, or bottom rim for hollow columns. `None` if disabled
+    pub bottom: Option<MeshInfo>,
 }
 
 impl<'l> ColumnMeshBuilder<'l> {
@@ -84,6 +117,12 @@ impl<'l> ColumnMeshBuilder<'l> {
             bottom_face: Some(PlaneMeshBuilder::new(layout)),
             sides_options: [Some(FaceOptions::new()); 6],
             center_aligned: false,
+            smooth_normals: false,
+            wall_thickness: None,
+            centered_pivot: false,
+            triplanar_uvs: None,
+            corner_heights: None,
+            bevel: None,
         }
     }
 
@@ -254,6 +293,91 @@ impl<'l> ColumnMeshBuilder<'l> {
         self
     }
 
+    #[must_use]
+    /// Maps the uvs of each side quad in its texture atlas region, indexed by
+    /// [`EdgeDirection`].
+    ///
+    /// Only the [`UVOptions::rect`] of each side is replaced, other options
+    /// are kept and disabled sides stay disabled. Combined with
+    /// [`Self::with_caps_uv_regions`], a whole tileset can use a single
+    /// material.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// // A 4x2 texture atlas
+    /// let cell = |x: f32, y: f32| Rect {
+    ///     min: Vec2::new(x, y) * Vec2::new(0.25, 0.5),
+    ///     max: Vec2::new(x + 1.0, y + 1.0) * Vec2::new(0.25, 0.5),
+    /// };
+    /// let mesh = ColumnMeshBuilder::new(&layout, 2.0)
+    ///     .with_side_uv_regions([0.0, 1.0, 2.0, 3.0, 0.0, 1.0].map(|x| cell(x, 1.0)))
+    ///     .with_caps_uv_regions(cell(2.0, 0.0), cell(3.0, 0.0))
+    ///     .build();
+    /// ```
+    pub fn with_side_uv_regions(mut self, regions: [Rect; 6]) -> Self {
+        for (options, rect) in self.sides_options.iter_mut().zip(regions) {
+            if let Some(options) = options {
+                options.uv.rect = rect;
+            }
+        }
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Maps the uvs of the `top` and `bottom` caps in their texture atlas
+    /// region, replacing only their [`UVOptions::rect`].
+    ///
+    /// See [`Self::with_side_uv_regions`]
+    ///
+    /// Note:
+    /// this won't have any effect if `top_face` and `bottom_face` are disabled
+    pub const fn with_caps_uv_regions(mut self, top: Rect, bottom: Rect) -> Self {
+        if let Some(builder) = &mut self.top_face {
+            builder.face_options.uv.rect = top;
+        }
+        if let Some(builder) = &mut self.bottom_face {
+            builder.face_options.uv.rect = bottom;
+        }
+        self
+    }
+
+    #[must_use]
+    /// Only generates the side quads in the given `sides` directions, the
+    /// other sides are removed. The options of the kept sides are unchanged.
+    ///
+    /// This is useful to skip sides hidden by a neighbor column of the same
+    /// height.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let sides = DirectionMask::from(EdgeDirection::FLAT_TOP) | EdgeDirection::FLAT_BOTTOM;
+    /// let mesh = ColumnMeshBuilder::new(&layout, 2.0)
+    ///     .with_sides(sides)
+    ///     .without_top_face()
+    ///     .without_bottom_face()
+    ///     .build();
+    /// assert_eq!(mesh.vertices.len(), 8);
+    /// ```
+    pub fn with_sides(mut self, sides: impl Into<DirectionMask>) -> Self {
+        let sides = sides.into();
+        for (dir, options) in EdgeDirection::ALL_DIRECTIONS
+            .into_iter()
+            .zip(&mut self.sides_options)
+        {
+            if !sides.contains(dir) {
+                *options = None;
+            }
+        }
+        self
+    }
+
     #[must_use]
     #[inline]
     /// Ignores the [`HexLayout::origin`] offset, generating a mesh centered
@@ -264,55 +388,386 @@ impl<'l> ColumnMeshBuilder<'l> {
     }
 
     #[must_use]
-    #[allow(clippy::cast_precision_loss)]
-    #[allow(clippy::many_single_char_names)]
-    /// Comsumes the builder to return the computed mesh data
-    pub fn build(self) -> MeshInfo {
-        // We store the offset to match the `self.pos`
-        let pos = if self.center_aligned {
-            self.layout.hex_to_center_aligned_world_pos(self.pos)
-        } else {
-            self.layout.hex_to_world_pos(self.pos)
-        };
-        let mut offset = Vec3::new(pos.x, 0.0, pos.y);
-        // We create the final mesh
+    #[inline]
+    /// Specify whether the mesh should have *smooth* normals, averaged between
+    /// faces sharing a vertex, or *flat* per face normals (default).
+    ///
+    /// See [`MeshInfo::with_smooth_normals`]
+    pub const fn with_smooth_normals(mut self, smooth: bool) -> Self {
+        self.smooth_normals = smooth;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Makes the column a hollow tube with walls of the given `thickness`.
+    ///
+    /// The side walls are generated twice, facing outwards and inwards, and
+    /// the top and bottom faces are replaced by *rims* connecting them. Use
+    /// [`Self::without_top_face`] and [`Self::without_bottom_face`] to
+    /// remove the rims.
+    ///
+    /// The rims use the caps UV options but ignore the caps inset options.
+    ///
+    /// # Note
+    ///
+    /// The `thickness` should be lower than the distance between the hexagon
+    /// center and its edges, otherwise the inner walls will be inverted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let pipe = ColumnMeshBuilder::new(&layout, 10.0)
+    ///     .with_wall_thickness(0.1)
+    ///     .build();
+    /// ```
+    pub const fn with_wall_thickness(mut self, thickness: f32) -> Self {
+        self.wall_thickness = Some(thickness);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Replaces the uvs of every face with *triplanar* uvs, computed from the
+    /// final vertex positions multiplied by `scale`.
+    ///
+    /// The mesh texture is then aligned in world space across columns of
+    /// different heights, which is useful for terrain cliffs.
+    /// The faces uv options are ignored.
+    ///
+    /// See [`MeshInfo::with_triplanar_uvs`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let cliff = ColumnMeshBuilder::new(&layout, 10.0)
+    ///     .at(hex(3, -1))
+    ///     .with_triplanar_uvs(0.25)
+    ///     .build();
+    /// ```
+    pub const fn with_triplanar_uvs(mut self, scale: f32) -> Self {
+        self.triplanar_uvs = Some(scale);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Specify a custom top height for each of the 6 corners, indexed by
+    /// [`VertexDirection`], replacing the column `height`.
+    ///
+    /// The side quads become trapezoids following the corners heights and
+    /// the top face is sloped accordingly, with recomputed normals. Useful for
+    /// cliffs and slopes between neighbors of different heights.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let slope = ColumnMeshBuilder::new(&layout, 1.0)
+    ///     .with_corner_heights([1.0, 1.0, 1.5, 2.0, 2.0, 1.5])
+    ///     .build();
+    /// let max_height = slope.vertices.iter().map(|v| v.y).fold(0.0, f32::max);
+    /// assert_eq!(max_height, 2.0);
+    /// ```
+    pub const fn with_corner_heights(mut self, heights: [f32; 6]) -> Self {
+        self.corner_heights = Some(heights);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Bevels the edges between the top face and the sides with a 45° chamfer
+    /// of the given `size`.
+    ///
+    /// The top face is inset by `size` and the sides stop `size` below it, a
+    /// ring of chamfer quads connecting them. The column keeps its total
+    /// height and the chamfer has smooth normals. The chamfer is part of
+    /// [`ColumnMeshParts::top`] and is not generated without a top face.
+    ///
+    /// # Note
+    ///
+    /// The bevel is ignored for hollow columns (see
+    /// [`Self::with_wall_thickness`]). The `size` should be lower than the
+    /// column height and the distance between the hexagon center and its
+    /// edges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let tile = ColumnMeshBuilder::new(&layout, 1.0)
+    ///     .with_bevel(0.1)
+    ///     .build();
+    /// ```
+    pub const fn with_bevel(mut self, size: f32) -> Self {
+        self.bevel = Some(size);
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Anchors the mesh at the center of its bounding box instead of the
+    /// center of the bottom face, before applying scale, rotation and offsets.
+    ///
+    /// The bounding box only accounts for the generated faces, so a column
+    /// without sides and bottom face is centered on its top face.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// // Equivalent of `.with_offset(Vec3::NEG_Y * 10.0 / 2.0)`
+    /// let mesh = ColumnMeshBuilder::new(&layout, 10.0)
+    ///     .centered_pivot()
+    ///     .build();
+    /// ```
+    pub const fn centered_pivot(mut self) -> Self {
+        self.centered_pivot = true;
+        self
+    }
+
+    /// Computes the `vertex` corner offsetted inwards by `thickness`, at
+    /// `thickness` distance of both its edges
+    fn inner_corner(&self, vertex: VertexDirection, thickness: f32) -> Vec2 {
+        let [a, b] = vertex
+            .edge_directions()
+            .map(|dir| self.layout.edge_normal(dir));
+        let offset = Mat2::from_cols(a, b).transpose().inverse() * Vec2::splat(-thickness);
+        vertex.world_unit_vector(self.layout) + offset
+    }
+
+    /// Computes the inner walls edge corners, offsetted inwards by `thickness`
+    fn inner_edge_corners(&self, thickness: f32) -> [[Vec2; 2]; 6] {
+        EdgeDirection::ALL_DIRECTIONS.map(|dir| {
+            dir.vertex_directions()
+                .map(|v| self.inner_corner(v, thickness))
+        })
+    }
+
+    /// Computes a rim face connecting the `outer` and `inner` edges at the
+    /// given edge corners `heights`, with the `outer` edges lowered by
+    /// `outer_drop`
+    pub(crate) fn rim(
+        outer: &[[Vec2; 2]; 6],
+        inner: &[[Vec2; 2]; 6],
+        heights: &[[f32; 2]; 6],
+        outer_drop: f32,
+        uv_options: &UVOptions,
+        flip: bool,
+    ) -> MeshInfo {
+        let radius = outer
+            .iter()
+            .flatten()
+            .map(|p| p.length())
+            .fold(0.0, f32::max);
         let mut mesh = MeshInfo::default();
+        for (([outer_left, outer_right], [inner_left, inner_right]), [left_h, right_h]) in
+            outer.iter().zip(inner).zip(heights)
+        {
+            let points = [*outer_right, *outer_left, *inner_left, *inner_right];
+            let heights = [right_h - outer_drop, left_h - outer_drop, *left_h, *right_h];
+            let mut uvs = points.map(|p| p / radius / 2.0 + Vec2::splat(0.5));
+            uv_options.alter_uvs(&mut uvs);
+            let mut quad = MeshInfo {
+                vertices: points
+                    .into_iter()
+                    .zip(heights)
+                    .map(|(p, h)| Vec3::new(p.x, h, p.y))
+                    .collect(),
+                normals: vec![BASE_FACING; 4],
+                uvs: uvs.to_vec(),
+                indices: vec![0, 1, 2, 2, 3, 0],
+            };
+            if flip {
+                quad.flip_winding();
+            }
+            mesh.merge_with(quad);
+        }
+        mesh
+    }
+
+    /// Finds the (center, `i`, `i + 1`) triangle of the `corners` fan
+    /// containing the center aligned `point`, returning `i` and the
+    /// barycentric coordinates of `point` for both corners
+    fn fan_coords(corners: &[Vec2; 6], point: Vec2) -> (usize, [f32; 2]) {
+        (0..6)
+            .map(|i| {
+                let j = (i + 1) % 6;
+                let coords = Mat2::from_cols(corners[i], corners[j]).inverse() * point;
+                (i, coords.to_array())
+            })
+            .max_by(|(_, a), (_, b)| a[0].min(a[1]).total_cmp(&b[0].min(b[1])))
+            .unwrap_or((0, [0.0; 2]))
+    }
+
+    /// Computes the height of the top face at the center aligned `point`,
+    /// linearly interpolated between the two closest `corners` and the hexagon
+    /// center, which has the average corner height.
+    fn top_height_at(corners: &[Vec2; 6], heights: &[f32; 6], point: Vec2) -> f32 {
+        let center = heights.iter().sum::<f32>() / 6.0;
+        let (i, [a, b]) = Self::fan_coords(corners, point);
+        let j = (i + 1) % 6;
+        Vec3::new(a, b, 1.0 - a - b).dot(Vec3::new(heights[i], heights[j], center))
+    }
+
+    /// Moves the center aligned `point` of the `corners` hexagon to the same
+    /// relative position in the `inset` hexagon
+    fn inset_point(corners: &[Vec2; 6], inset: &[Vec2; 6], point: Vec2) -> Vec2 {
+        let (i, [a, b]) = Self::fan_coords(corners, point);
+        inset[i] * a + inset[(i + 1) % 6] * b
+    }
+
+    /// Computes the column sides, top and bottom faces around the origin
+    #[allow(clippy::cast_precision_loss)]
+    fn build_raw_parts(&self) -> ColumnMeshParts {
+        let mut sides = MeshInfo::default();
         // Column sides
         let subidivisions = self.subdivisions.unwrap_or(0).max(1);
-        let delta = self.height / subidivisions as f32;
+        let heights = self.corner_heights.unwrap_or([self.height; 6]);
+        // Edge corners heights, ordered like the edge corners
+        let edge_heights = EdgeDirection::ALL_DIRECTIONS.map(|dir| {
+            dir.vertex_directions()
+                .map(|v| heights[usize::from(v.index())])
+        });
         let corners = self.layout.center_aligned_edge_corners();
+        let inner_corners = self.wall_thickness.map(|t| self.inner_edge_corners(t));
+        // The bevel lowers the sides top, only if there is a top face
+        let bevel = self
+            .bevel
+            .filter(|_| inner_corners.is_none() && self.top_face.is_some());
+        let side_heights = edge_heights.map(|h| h.map(|h| h - bevel.unwrap_or(0.0)));
+        let mirrored = self.layout.is_mirrored();
         (0..6).for_each(|side| {
-            let [left, right] = corners[side];
             let Some(options) = self.sides_options[side] else {
                 return;
             };
-            for div in 0..subidivisions {
-                let bottom_height = delta * div as f32;
-                let mut quad = Quad::new([left, right], bottom_height, bottom_height + delta);
-                options.uv.alter_uvs(&mut quad.uvs);
-                let quad = if let Some(opts) = options.insetting {
-                    quad.inset(opts.mode, opts.scale, opts.keep_inner_face)
-                } else {
-                    quad.into()
-                };
-                mesh.merge_with(quad);
+            let normal = self.layout.edge_normal(EdgeDirection::ALL_DIRECTIONS[side]);
+            // Outer wall, and optional inner wall facing inwards
+            let walls = std::iter::once((corners[side], normal, false))
+                .chain(inner_corners.map(|inner| (inner[side], -normal, true)));
+            let [left_delta, right_delta] = side_heights[side].map(|h| h / subidivisions as f32);
+            for ([left, right], normal, inwards) in walls {
+                for div in 0..subidivisions {
+                    let bottom_height = right_delta * div as f32;
+                    let mut quad =
+                        Quad::new([left, right], bottom_height, bottom_height + right_delta);
+                    // Trapezoid sides for custom corner heights
+                    let left_bottom_height = left_delta * div as f32;
+                    quad.positions[2].y = left_bottom_height + left_delta;
+                    quad.positions[3].y = left_bottom_height;
+                    quad.normals = [Vec3::new(normal.x, 0.0, normal.y); 4];
+                    options.uv.alter_uvs(&mut quad.uvs);
+                    let mut quad = if let Some(opts) = options.insetting {
+                        quad.inset(opts.mode, opts.scale, opts.keep_inner_face)
+                    } else {
+                        quad.into()
+                    };
+                    // Mirrored layouts have corners in reverse order
+                    if mirrored != inwards {
+                        quad.flip_winding();
+                    }
+                    sides.merge_with(quad);
+                }
             }
         });
-        // Hexagon top face
-        if let Some(builder) = self.top_face {
-            mesh.merge_with(
-                builder
-                    .center_aligned()
-                    .with_offset(Vec3::Y * self.height)
-                    .build(),
+        let rotation = Quat::from_rotation_arc(BASE_FACING, -BASE_FACING);
+        // Hexagon faces, or rims for hollow tubes
+        let top = self.top_face.as_ref().map(|builder| {
+            let mut mesh = inner_corners.map_or_else(
+                || {
+                    let builder = builder.clone().center_aligned();
+                    if self.corner_heights.is_some() {
+                        builder.build()
+                    } else {
+                        builder.with_offset(Vec3::Y * self.height).build()
+                    }
+                },
+                |inner| {
+                    let uv = builder.face_options.uv;
+                    Self::rim(&corners, &inner, &edge_heights, 0.0, &uv, mirrored)
+                },
             );
+            let hex_corners = self.layout.corner_offsets();
+            // Sloped top face
+            if self.corner_heights.is_some() {
+                if inner_corners.is_none() {
+                    for v in &mut mesh.vertices {
+                        v.y = Self::top_height_at(&hex_corners, &heights, Vec2::new(v.x, v.z));
+                    }
+                }
+                mesh.recompute_normals();
+            }
+            // Inset top face and chamfer ring
+            if let Some(size) = bevel {
+                let inset = self.inner_edge_corners(size);
+                let inset_corners =
+                    VertexDirection::ALL_DIRECTIONS.map(|v| self.inner_corner(v, size));
+                for v in &mut mesh.vertices {
+                    let p = Self::inset_point(&hex_corners, &inset_corners, Vec2::new(v.x, v.z));
+                    [v.x, v.z] = p.to_array();
+                }
+                let uv = builder.face_options.uv;
+                let mut chamfer = Self::rim(&corners, &inset, &edge_heights, size, &uv, mirrored);
+                chamfer.recompute_normals();
+                mesh.merge_with(chamfer.with_smooth_normals());
+            }
+            mesh
+        });
+        let bottom = self.bottom_face.as_ref().map(|builder| {
+            inner_corners
+                .map_or_else(
+                    || builder.clone().center_aligned().build(),
+                    |inner| {
+                        let uv = builder.face_options.uv;
+                        Self::rim(&corners, &inner, &[[0.0; 2]; 6], 0.0, &uv, mirrored)
+                    },
+                )
+                .rotated(rotation)
+        });
+        ColumnMeshParts { top, sides, bottom }
+    }
+
+    /// Computes the pivot offset to apply before the transformations
+    fn pivot_offset(&self, parts: &ColumnMeshParts) -> Vec3 {
+        if !self.centered_pivot {
+            return Vec3::ZERO;
         }
-        // Hexagon bottom face
-        if let Some(builder) = self.bottom_face {
-            let rotation = Quat::from_rotation_arc(BASE_FACING, -BASE_FACING);
-            let bottom_face = builder.center_aligned().build().rotated(rotation);
-            mesh.merge_with(bottom_face);
+        let vertices = parts
+            .top
+            .iter()
+            .chain(parts.bottom.iter())
+            .chain(std::iter::once(&parts.sides))
+            .flat_map(|mesh| &mesh.vertices);
+        let [min, max] = vertices.fold([Vec3::MAX, Vec3::MIN], |[min, max], v| {
+            [min.min(*v), max.max(*v)]
+        });
+        if min.cmpgt(max).any() {
+            return Vec3::ZERO;
+        }
+        -min.midpoint(max)
+    }
+
+    /// Applies the pivot, the smooth normals option, the **SRT**
+    /// transformations and the triplanar uvs option to `mesh`
+    fn transform(&self, mut mesh: MeshInfo, pivot: Vec3) -> MeshInfo {
+        // We store the offset to match the `self.pos`
+        let pos = if self.center_aligned {
+            self.layout.hex_to_center_aligned_world_pos(self.pos)
+        } else {
+            self.layout.hex_to_world_pos(self.pos)
+        };
+        let mut offset = Vec3::new(pos.x, 0.0, pos.y);
+        if self.smooth_normals {
+            mesh = mesh.with_smooth_normals();
         }
+        mesh = mesh.with_offset(pivot);
         // **S** - We apply optional scale
         if let Some(scale) = self.scale {
             mesh = mesh.with_scale(scale);
@@ -326,6 +781,60 @@ impl<'l> ColumnMeshBuilder<'l> {
             offset += custom_offset;
         }
         mesh = mesh.with_offset(offset);
+        if let Some(scale) = self.triplanar_uvs {
+            mesh = mesh.with_triplanar_uvs(scale);
+        }
         mesh
     }
+
+    #[must_use]
+    /// Comsumes the builder to return the computed mesh data
+    pub fn build(self) -> MeshInfo {
+        let parts = self.build_raw_parts();
+        let pivot = self.pivot_offset(&parts);
+        let mut mesh = parts.sides;
+        // Hexagon top face
+        if let Some(top) = parts.top {
+            mesh.merge_with(top);
+        }
+        // Hexagon bottom face
+        if let Some(bottom) = parts.bottom {
+            mesh.merge_with(bottom);
+        }
+        self.transform(mesh, pivot)
+    }
+
+    #[must_use]
+    /// Comsumes the builder to return the computed mesh data split in separate
+    /// meshes for the top face, the sides and the bottom face.
+    ///
+    /// This is useful to use different materials on the caps and the sides.
+    /// Every part gets the same transformations as [`Self::build`], so merging
+    /// them gives the same geometry.
+    ///
+    /// # Note
+    ///
+    /// If [`Self::smooth_normals`] is enabled, normals are averaged in each
+    /// part separately
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let parts = ColumnMeshBuilder::new(&layout, 5.0)
+    ///     .without_bottom_face()
+    ///     .build_parts();
+    /// assert!(parts.top.is_some());
+    /// assert!(parts.bottom.is_none());
+    /// ```
+    pub fn build_parts(self) -> ColumnMeshParts {
+        let parts = self.build_raw_parts();
+        let pivot = self.pivot_offset(&parts);
+        ColumnMeshParts {
+            top: parts.top.map(|mesh| self.transform(mesh, pivot)),
+            sides: self.transform(parts.sides, pivot),
+            bottom: parts.bottom.map(|mesh| self.transform(mesh, pivot)),
+        }
+    }
 }
@@ -0,0 +1,245 @@
+use super::{MeshIndex, MeshInfo, WELD_PRECISION};
+use glam::{IVec3, Vec2, Vec3};
+use std::collections::{HashMap, HashSet};
+
+type Triangle = [MeshIndex; 3];
+
+/// Minimum sine of the angle between two vectors to not be considered
+/// collinear
+const COLLINEAR_EPSILON: f32 = 1e-4;
+
+/// Checks if going from `a` to `b` is a strict counter clockwise turn
+fn turns_left(a: Vec2, b: Vec2) -> bool {
+    a.perp_dot(b) > COLLINEAR_EPSILON * a.length() * b.length()
+}
+
+/// Finds the root of `i` in the `parents` disjoint set forest
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+/// Checks if `p` is inside or on the border of the `[a, b, c]` counter
+/// clockwise triangle
+fn in_triangle(p: Vec2, [a, b, c]: [Vec2; 3]) -> bool {
+    [(a, b), (b, c), (c, a)]
+        .into_iter()
+        .all(|(from, to)| !turns_left(p - from, to - from))
+}
+
+/// Triangulates a simple counter clockwise `polygon` through *ear clipping*.
+///
+/// Returns `None` if the polygon could not be triangulated
+fn triangulate(polygon: &[Vec2]) -> Option<Vec<[usize; 3]>> {
+    let mut remaining: Vec<usize> = (0..polygon.len()).collect();
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+    while remaining.len() > 3 {
+        let len = remaining.len();
+        let ear = (0..len).find(|i| {
+            let tri = [(i + len - 1) % len, *i, (i + 1) % len].map(|j| remaining[j]);
+            let [a, b, c] = tri.map(|j| polygon[j]);
+            // The vertex must be convex and the ear must not contain other vertices
+            turns_left(b - a, c - b)
+                && remaining
+                    .iter()
+                    .filter(|j| !tri.contains(j))
+                    .all(|j| !in_triangle(polygon[*j], [a, b, c]))
+        })?;
+        triangles.push([(ear + len - 1) % len, ear, (ear + 1) % len].map(|j| remaining[j]));
+        remaining.remove(ear);
+    }
+    if let [a, b, c] = remaining[..] {
+        if turns_left(polygon[b] - polygon[a], polygon[c] - polygon[b]) {
+            triangles.push([a, b, c]);
+        }
+    }
+    Some(triangles)
+}
+
+impl MeshInfo {
+    /// Merges adjacent coplanar triangles into larger polygons, and
+    /// triangulates them again with fewer triangles.
+    ///
+    /// Two adjacent triangles are considered coplanar if their normals differ
+    /// by at most `normal_epsilon` on each axis. Only *flat shaded* triangles,
+    /// with vertex normals matching their face normal, are merged.
+    ///
+    /// Merged regions with holes or non manifold borders are left untouched.
+    /// Unused vertices are removed.
+    ///
+    /// This is useful to reduce the triangle count of large flat areas, like
+    /// plains of merged hexagonal caps.
+    ///
+    /// # Note
+    ///
+    /// The merged triangles reuse the existing vertices, so the UV mapping of
+    /// merged regions may be altered if adjacent faces don't share their UVs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let mut mesh = MeshInfo::default();
+    /// for hex in Hex::ZERO.range(5) {
+    ///     mesh.merge_with(PlaneMeshBuilder::new(&layout).at(hex).build());
+    /// }
+    /// let triangle_count = mesh.indices.len() / 3;
+    /// mesh.merge_coplanar(0.001);
+    /// assert!(mesh.indices.len() / 3 < triangle_count);
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn merge_coplanar(&mut self, normal_epsilon: f32) {
+        let keys: Vec<IVec3> = self
+            .vertices
+            .iter()
+            .map(|v| (*v * WELD_PRECISION).round().as_ivec3())
+            .collect();
+        let key = |i: MeshIndex| keys[i as usize];
+        let triangles: Vec<Triangle> = self
+            .indices
+            .chunks_exact(3)
+            .map(|t| [t[0], t[1], t[2]])
+            .collect();
+        // Flat shaded face normals
+        let face_normals: Vec<Option<Vec3>> = triangles
+            .iter()
+            .map(|tri| {
+                let [a, b, c] = tri.map(|i| self.vertices[i as usize]);
+                let normal = (b - a).cross(c - a).try_normalize()?;
+                tri.iter()
+                    .all(|i| self.normals[*i as usize].abs_diff_eq(normal, normal_epsilon))
+                    .then_some(normal)
+            })
+            .collect();
+        let mut edges = HashMap::with_capacity(self.indices.len());
+        for (t, tri) in triangles.iter().enumerate() {
+            if face_normals[t].is_some() {
+                for e in 0..3 {
+                    edges.insert((key(tri[e]), key(tri[(e + 1) % 3])), t);
+                }
+            }
+        }
+        // Coplanar regions
+        let mut parents: Vec<usize> = (0..triangles.len()).collect();
+        for (&(a, b), &t) in &edges {
+            let Some(&other) = edges.get(&(b, a)) else {
+                continue;
+            };
+            if let (Some(n1), Some(n2)) = (face_normals[t], face_normals[other]) {
+                if n1.abs_diff_eq(n2, normal_epsilon) {
+                    let [r1, r2] = [t, other].map(|i| find_root(&mut parents, i));
+                    parents[r1] = r2;
+                }
+            }
+        }
+        let mut regions: HashMap<usize, Vec<usize>> = HashMap::new();
+        for t in 0..triangles.len() {
+            let root = find_root(&mut parents, t);
+            regions.entry(root).or_default().push(t);
+        }
+        let mut indices = Vec::with_capacity(self.indices.len());
+        for t in 0..triangles.len() {
+            let region = &regions[&find_root(&mut parents, t)];
+            // Each region is processed once, on its first triangle
+            if region[0] != t {
+                continue;
+            }
+            let region_triangles: Vec<Triangle> = region.iter().map(|t| triangles[*t]).collect();
+            let merged = face_normals[t]
+                .filter(|_| region.len() > 2)
+                .and_then(|normal| self.triangulate_region(&region_triangles, normal, &key))
+                .filter(|merged| merged.len() < region.len());
+            indices.extend(merged.unwrap_or(region_triangles).into_iter().flatten());
+        }
+        self.indices = indices;
+        self.remove_unused_vertices();
+    }
+
+    /// Computes the border of a coplanar region and triangulates it
+    fn triangulate_region(
+        &self,
+        triangles: &[Triangle],
+        normal: Vec3,
+        key: &impl Fn(MeshIndex) -> IVec3,
+    ) -> Option<Vec<Triangle>> {
+        let edges: HashSet<(IVec3, IVec3)> = triangles
+            .iter()
+            .flat_map(|tri| (0..3).map(|e| (key(tri[e]), key(tri[(e + 1) % 3]))))
+            .collect();
+        let mut vertex_indices = HashMap::new();
+        let mut next = HashMap::new();
+        for tri in triangles {
+            for e in 0..3 {
+                let [a, b] = [tri[e], tri[(e + 1) % 3]];
+                vertex_indices.entry(key(a)).or_insert(a);
+                if !edges.contains(&(key(b), key(a))) && next.insert(key(a), key(b)).is_some() {
+                    // Non manifold border
+                    return None;
+                }
+            }
+        }
+        // Border loop
+        let start = *next.keys().next()?;
+        let mut border = vec![vertex_indices[&start]];
+        let mut current = next[&start];
+        while current != start && border.len() < next.len() {
+            border.push(vertex_indices[&current]);
+            current = next[&current];
+        }
+        if current != start || border.len() != next.len() {
+            // Multiple loops, the region has holes
+            return None;
+        }
+        let (mut u, mut v) = normal.any_orthonormal_pair();
+        if u.cross(v).dot(normal) < 0.0 {
+            std::mem::swap(&mut u, &mut v);
+        }
+        let polygon: Vec<Vec2> = border
+            .iter()
+            .map(|i| {
+                let p = self.vertices[*i as usize];
+                Vec2::new(p.dot(u), p.dot(v))
+            })
+            .collect();
+        let triangles = triangulate(&polygon)?;
+        Some(
+            triangles
+                .into_iter()
+                .map(|t| t.map(|i| border[i]))
+                .collect(),
+        )
+    }
+
+    /// Removes vertices not referenced by any triangle
+    #[allow(clippy::cast_possible_truncation)]
+    fn remove_unused_vertices(&mut self) {
+        let mut remap = vec![None; self.vertices.len()];
+        let mut count = 0;
+        for i in &self.indices {
+            remap[*i as usize].get_or_insert_with(|| {
+                count += 1;
+                count - 1
+            });
+        }
+        let keep = |i: &usize| remap[*i].is_some();
+        let mut vertices = vec![Vec3::ZERO; count as usize];
+        let mut normals = vec![Vec3::ZERO; count as usize];
+        let mut uvs = vec![Vec2::ZERO; count as usize];
+        for i in (0..self.vertices.len()).filter(keep) {
+            let new = remap[i].unwrap_or_default() as usize;
+            vertices[new] = self.vertices[i];
+            normals[new] = self.normals[i];
+            uvs[new] = self.uvs[i];
+        }
+        for i in &mut self.indices {
+            *i = remap[*i as usize].unwrap_or_default();
+        }
+        self.vertices = vertices;
+        self.normals = normals;
+        self.uvs = uvs;
+    }
+}
@@ -0,0 +1,154 @@
+use super::{MeshIndex, MeshInfo};
+use glam::{IVec3, Vec2, Vec3};
+use std::collections::HashMap;
+
+/// Amount of cell size refinement steps during decimation
+const DECIMATION_STEPS: usize = 16;
+/// Normal quantization factor, vertices with different enough normals are not
+/// clustered together to preserve sharp edges
+const NORMAL_PRECISION: f32 = 2.0;
+
+/// Quantized position and normal of a vertex
+type ClusterKey = (IVec3, IVec3);
+
+/// Accumulated attributes of merged vertices
+#[derive(Default)]
+struct Cluster {
+    position: Vec3,
+    normal: Vec3,
+    uv: Vec2,
+    count: f32,
+}
+
+impl MeshInfo {
+    /// Returns a decimated version of the mesh, with a triangle count reduced
+    /// towards `target_ratio` (`0.0..=1.0`) of the original triangle count.
+    ///
+    /// This uses *vertex clustering*: vertices are grouped in a regular grid
+    /// whose cell size is adjusted to match the target ratio, and each group is
+    /// merged into a single vertex. Vertices with very different normals are
+    /// not merged to preserve the mesh silhouette and sharp edges.
+    ///
+    /// The resulting triangle count is the closest found to the target while
+    /// being lower or equal to it, and degenerate triangles are removed.
+    /// A non-empty mesh is never decimated to an empty mesh: if no clustering
+    /// reaches the target, the coarsest clustering with triangles is returned.
+    ///
+    /// # Note
+    ///
+    /// Merged vertices get the average of their UV coordinates, so textured
+    /// meshes may be distorted. This is intended for distant *LOD* meshes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let mesh = ColumnMeshBuilder::new(&layout, 10.0)
+    ///     .with_subdivisions(10)
+    ///     .build();
+    /// let lod = mesh.decimate(0.5);
+    /// assert!(lod.indices.len() <= mesh.indices.len() / 2);
+    /// ```
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn decimate(&self, target_ratio: f32) -> Self {
+        let triangle_count = self.indices.len() / 3;
+        let target = (triangle_count as f32 * target_ratio.clamp(0.0, 1.0)).floor() as usize;
+        if target >= triangle_count {
+            return self.clone();
+        }
+        let min = self
+            .vertices
+            .iter()
+            .copied()
+            .reduce(Vec3::min)
+            .unwrap_or_default();
+        let max = self
+            .vertices
+            .iter()
+            .copied()
+            .reduce(Vec3::max)
+            .unwrap_or_default();
+        // With a cell bigger than the mesh every triangle is degenerate
+        let [mut low, mut high] = [0.0, (max - min).max_element().mul_add(2.0, f32::EPSILON)];
+        // Finest clustering under the target, and coarsest non-empty clustering
+        let mut best: Option<Self> = None;
+        let mut fallback: Option<Self> = None;
+        for _ in 0..DECIMATION_STEPS {
+            let cell_size = low + (high - low) / 2.0;
+            let mesh = self.clustered(min, cell_size);
+            let count = mesh.indices.len() / 3;
+            if count <= target {
+                high = cell_size;
+            } else {
+                low = cell_size;
+            }
+            if count == 0 {
+                continue;
+            }
+            if count <= target {
+                if best.as_ref().is_none_or(|b| b.indices.len() / 3 < count) {
+                    best = Some(mesh);
+                }
+            } else if fallback
+                .as_ref()
+                .is_none_or(|f| f.indices.len() / 3 > count)
+            {
+                fallback = Some(mesh);
+            }
+        }
+        best.or(fallback).unwrap_or_else(|| self.clone())
+    }
+
+    /// Merges vertices in grid cells of the given `cell_size`, starting at
+    /// `min`
+    #[allow(clippy::cast_possible_truncation)]
+    fn clustered(&self, min: Vec3, cell_size: f32) -> Self {
+        let mut indices_map: HashMap<ClusterKey, MeshIndex> = HashMap::new();
+        let mut clusters: Vec<Cluster> = Vec::new();
+        let remap: Vec<MeshIndex> = self
+            .vertices
+            .iter()
+            .zip(&self.normals)
+            .zip(&self.uvs)
+            .map(|((position, normal), uv)| {
+                let key = (
+                    ((*position - min) / cell_size).floor().as_ivec3(),
+                    (*normal * NORMAL_PRECISION).round().as_ivec3(),
+                );
+                let index = *indices_map.entry(key).or_insert_with(|| {
+                    clusters.push(Cluster::default());
+                    // Clusters can't outnumber the original vertices
+                    (clusters.len() - 1) as MeshIndex
+                });
+                let cluster = &mut clusters[index as usize];
+                cluster.position += *position;
+                cluster.normal += *normal;
+                cluster.uv += *uv;
+                cluster.count += 1.0;
+                index
+            })
+            .collect();
+        let indices = self
+            .indices
+            .chunks_exact(3)
+            .map(|tri| [tri[0], tri[1], tri[2]].map(|i| remap[i as usize]))
+            .filter(|[a, b, c]| a != b && b != c && a != c)
+            .flatten()
+            .collect();
+        Self {
+            vertices: clusters.iter().map(|c| c.position / c.count).collect(),
+            normals: clusters
+                .iter()
+                .map(|c| c.normal.try_normalize().unwrap_or(Vec3::Y))
+                .collect(),
+            uvs: clusters.iter().map(|c| c.uv / c.count).collect(),
+            indices,
+        }
+    }
+}
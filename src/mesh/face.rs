@@ -1,9 +1,12 @@
-use crate::{HexLayout, InsetScaleMode, MeshInfo, UVOptions, BASE_FACING};
+use crate::{
+    storage::{HexStore, HexagonalMap},
+    Hex, HexLayout, InsetScaleMode, MeshInfo, UVOptions, BASE_FACING,
+};
 use glam::{Vec2, Vec3};
 
-use super::FaceOptions;
+use super::{FaceOptions, MeshIndex};
 
-type VertexIdx = u16;
+type VertexIdx = MeshIndex;
 
 /// Structure storing three vertex indices
 #[derive(Debug, Clone, Copy)]
@@ -47,7 +50,40 @@ impl Quad {
     /// * `bottom_height` - the bottom vertices Y value
     /// * `top_height` - the top vertices Y value
     #[must_use]
-    pub fn new([left, right]: [Vec2; 2], bottom_height: f32, top_height: f32) -> Self {
+    pub fn new(sides: [Vec2; 2], bottom_height: f32, top_height: f32) -> Self {
+        Self::new_with_uvs(
+            sides,
+            bottom_height,
+            top_height,
+            [Vec2::X, Vec2::ONE, Vec2::Y, Vec2::ZERO],
+        )
+    }
+
+    /// Same as [`Quad::new`] but with custom vertex `uvs`, allowing custom
+    /// texture mapping.
+    ///
+    /// The `uvs` are in the following order:
+    /// `[bottom_right, top_right, top_left, bottom_left]`, [`Quad::new`]
+    /// uses `[(1, 0), (1, 1), (0, 1), (0, 0)]`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::{*, face::Quad};
+    /// let layout = HexLayout::default();
+    /// let [left, right] = layout.center_aligned_edge_corners()[0];
+    /// // A banner covering the upper half of a texture
+    /// let uvs = [Vec2::new(1.0, 0.5), Vec2::ONE, Vec2::Y, Vec2::new(0.0, 0.5)];
+    /// let mut mesh = MeshInfo::default();
+    /// mesh.merge_with(Quad::new_with_uvs([left, right], 0.0, 2.0, uvs).into());
+    /// ```
+    #[must_use]
+    pub fn new_with_uvs(
+        [left, right]: [Vec2; 2],
+        bottom_height: f32,
+        top_height: f32,
+        uvs: [Vec2; 4],
+    ) -> Self {
         let normal = (left + right).normalize();
         let normal = Vec3::new(normal.x, 0.0, normal.y);
         let positions = [
@@ -59,7 +95,7 @@ impl Quad {
         Self {
             positions,
             normals: [normal; 4],
-            uvs: [Vec2::X, Vec2::ONE, Vec2::Y, Vec2::ZERO],
+            uvs,
             // 2 - 1
             // | \ |
             // 3 - 0
@@ -80,14 +116,19 @@ impl Quad {
         [min_height, max_height]: [f32; 2],
     ) -> Self {
         let delta = max_height - min_height;
-        let mut quad = Self::new(sides, bottom_height, top_height);
         let bottom_v = (bottom_height - min_height) / delta;
         let top_v = (top_height - min_height) / delta;
-        quad.uvs[0][1] = bottom_v;
-        quad.uvs[1][1] = top_v;
-        quad.uvs[2][1] = top_v;
-        quad.uvs[3][1] = bottom_v;
-        quad
+        Self::new_with_uvs(
+            sides,
+            bottom_height,
+            top_height,
+            [
+                Vec2::new(1.0, bottom_v),
+                Vec2::new(1.0, top_v),
+                Vec2::new(0.0, top_v),
+                Vec2::new(0.0, bottom_v),
+            ],
+        )
     }
 }
 
@@ -111,6 +152,76 @@ impl Hexagon {
             ],
         }
     }
+
+    /// Subdivides the hexagon into a triangular lattice, each of the `levels`
+    /// splitting every triangle in 4.
+    ///
+    /// The hexagon is first split into 6 triangles around its center, so the
+    /// resulting mesh has `2^levels` segments on each edge and
+    /// `3 * 4^levels + 3 * 2^levels + 1` shared vertices. Interior positions
+    /// and uvs are interpolated from the corners.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the mesh has more vertices than [`MeshIndex::MAX`], which
+    /// happens from 8 `levels`: 7 is the maximum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::{*, face::Hexagon};
+    /// let face = Hexagon::center_aligned(&HexLayout::default());
+    /// let mesh = face.subdivided(2);
+    /// assert_eq!(mesh.vertices.len(), 61);
+    /// assert_eq!(mesh.indices.len(), 96 * 3);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn subdivided(&self, levels: u32) -> MeshInfo {
+        // Axial directions of the corners in the lattice
+        const CORNERS: [Hex; 6] = [
+            Hex::new(1, 0),
+            Hex::new(0, 1),
+            Hex::new(-1, 1),
+            Hex::new(-1, 0),
+            Hex::new(0, -1),
+            Hex::new(1, -1),
+        ];
+        let size = 1_u32
+            .checked_shl(levels)
+            .expect("Too many subdivision levels");
+        let (center, uv_center) = (self.centroid(), self.uv_centroid());
+        let steps = self.positions.map(|p| (p - center) / size as f32);
+        let uv_steps = self.uvs.map(|uv| (uv - uv_center) / size as f32);
+        let mut mesh = MeshInfo::default();
+        // Lattice points are the axial coordinates in a hexagon of `size` radius
+        let lattice = HexagonalMap::new(Hex::ZERO, size, |h| {
+            // Coordinates of `h` in the (center, i, j) triangle containing it
+            let (i, a, b) = (0..6)
+                .map(|i| {
+                    let [u, v] = [CORNERS[i], CORNERS[(i + 1) % 6]];
+                    (i, h.x * v.y - h.y * v.x, u.x * h.y - u.y * h.x)
+                })
+                .find(|(_, a, b)| *a >= 0 && *b >= 0)
+                .unwrap_or_default();
+            let j = (i + 1) % 6;
+            let [a, b] = [a, b].map(|c| c as f32);
+            mesh.vertices.push(center + steps[i] * a + steps[j] * b);
+            mesh.uvs.push(uv_center + uv_steps[i] * a + uv_steps[j] * b);
+            mesh.normals.push(self.normals[0]);
+            VertexIdx::try_from(mesh.vertices.len() - 1)
+                .expect("Subdivided hexagon has too many vertices")
+        });
+        for h in Hex::ZERO.range(size) {
+            // Same orientation as the (center, corner 1, corner 0) triangle
+            for [b, c] in [[CORNERS[1], CORNERS[0]], [CORNERS[2], CORNERS[1]]] {
+                if let [Some(a), Some(b), Some(c)] = [h, h + b, h + c].map(|h| lattice.get(h)) {
+                    mesh.indices.extend([*a, *b, *c]);
+                }
+            }
+        }
+        mesh
+    }
 }
 
 impl<const VERTS: usize, const TRIS: usize> Face<VERTS, TRIS> {
@@ -148,7 +259,6 @@ impl<const VERTS: usize, const TRIS: usize> Face<VERTS, TRIS> {
     /// * `mode` - the insetting behaviour mode
     /// * `keep_inner_face` - If set to true the insetted face will be kept,
     ///   otherwise it will be removed
-    #[allow(clippy::cast_possible_truncation)]
     #[must_use]
     pub fn inset(self, mode: InsetScaleMode, scale: f32, keep_inner_face: bool) -> MeshInfo {
         // We compute the inset mesh, identical to the original face
@@ -167,36 +277,89 @@ impl<const VERTS: usize, const TRIS: usize> Face<VERTS, TRIS> {
                     *uv = *uv + ((uv_centroid - *uv) * scale);
                 });
             }
-            InsetScaleMode::SmallestEdge => {
-                let mut new_positions = inset_face.positions;
-                let mut new_uvs = inset_face.uvs;
-                for idx in 0..VERTS {
-                    let [prev_idx, next_idx] = [(idx + VERTS - 1) % VERTS, (idx + 1) % VERTS];
-                    // vertices
-                    let [pos, prev, next] =
-                        [idx, prev_idx, next_idx].map(|i| inset_face.positions[i]);
-                    let [dir_prev, dir_next] = [(prev - pos), (next - pos)];
-                    let [prev_len, next_len] = [dir_prev.length(), dir_next.length()];
-                    let dist = prev_len.min(next_len) * scale;
-                    new_positions[idx] =
-                        pos + dir_next.normalize() * dist + dir_prev.normalize() * dist;
-                    // uvs
-                    let [disp_prev, disp_next] = [dist / prev_len, dist / next_len];
-                    let [pos, prev, next] = [idx, prev_idx, next_idx].map(|i| inset_face.uvs[i]);
-                    let [dir_prev, dir_next] = [(prev - pos), (next - pos)];
-                    new_uvs[idx] = pos + dir_next * disp_next + dir_prev * disp_prev;
-                }
-                inset_face.positions = new_positions;
-                inset_face.uvs = new_uvs;
+            InsetScaleMode::SmallestEdge => inset_face.inset_vertices([scale; VERTS]),
+            InsetScaleMode::PerVertex(scales) => {
+                return self
+                    .inset_per_vertex(std::array::from_fn(|i| scales[i % 6]), keep_inner_face);
             }
         }
+        self.connect_inset(inset_face, [scale < 0.0; VERTS], keep_inner_face)
+    }
+
+    /// Performs an _inset_ operation on the mesh like [`Self::inset`] with
+    /// [`InsetScaleMode::SmallestEdge`], but with a custom scale for each
+    /// vertex, allowing uneven bevels.
+    ///
+    /// Each vertex is moved along its two edges by its `scales` factor of its
+    /// smallest edge length. Using the same scale for every vertex is
+    /// equivalent to [`Self::inset`].
+    ///
+    /// Negative scales move the vertices outwards. Each connecting quad faces
+    /// the same way as the face if the two vertices of its edge have the same
+    /// scale sign. Otherwise the quad is twisted, so avoid mixing signs on
+    /// adjacent vertices.
+    ///
+    /// # Arguments
+    ///
+    /// * `scales` - the inset scale factor of each vertex
+    /// * `keep_inner_face` - If set to true the insetted face will be kept,
+    ///   otherwise it will be removed
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::{*, face::Hexagon};
+    /// let layout = HexLayout::default();
+    /// let face = Hexagon::center_aligned(&layout);
+    /// let mesh = face.inset_per_vertex([0.1, 0.1, 0.3, 0.3, 0.1, 0.1], true);
+    /// ```
+    #[must_use]
+    pub fn inset_per_vertex(self, scales: [f32; VERTS], keep_inner_face: bool) -> MeshInfo {
+        let mut inset_face = self.clone();
+        inset_face.inset_vertices(scales);
+        let flip = std::array::from_fn(|i| scales[i] + scales[(i + 1) % VERTS] < 0.0);
+        self.connect_inset(inset_face, flip, keep_inner_face)
+    }
+
+    /// Moves every vertex position and uv along its two edges by its `scales`
+    /// factor of its smallest edge length
+    fn inset_vertices(&mut self, scales: [f32; VERTS]) {
+        let mut new_positions = self.positions;
+        let mut new_uvs = self.uvs;
+        for (idx, scale) in scales.into_iter().enumerate() {
+            let [prev_idx, next_idx] = [(idx + VERTS - 1) % VERTS, (idx + 1) % VERTS];
+            // vertices
+            let [pos, prev, next] = [idx, prev_idx, next_idx].map(|i| self.positions[i]);
+            let [dir_prev, dir_next] = [(prev - pos), (next - pos)];
+            let [prev_len, next_len] = [dir_prev.length(), dir_next.length()];
+            let dist = prev_len.min(next_len) * scale;
+            new_positions[idx] = pos + dir_next.normalize() * dist + dir_prev.normalize() * dist;
+            // uvs
+            let [disp_prev, disp_next] = [dist / prev_len, dist / next_len];
+            let [pos, prev, next] = [idx, prev_idx, next_idx].map(|i| self.uvs[i]);
+            let [dir_prev, dir_next] = [(prev - pos), (next - pos)];
+            new_uvs[idx] = pos + dir_next * disp_next + dir_prev * disp_prev;
+        }
+        self.positions = new_positions;
+        self.uvs = new_uvs;
+    }
+
+    /// Connects `self` to its `inset_face` with quads, flipping the quad of
+    /// each edge if its `flip` value is set
+    #[allow(clippy::cast_possible_truncation)]
+    fn connect_inset(
+        self,
+        inset_face: Self,
+        flip: [bool; VERTS],
+        keep_inner_face: bool,
+    ) -> MeshInfo {
         let mut inset_face = MeshInfo::from(inset_face);
         if !keep_inner_face {
             inset_face.indices.clear();
         }
         let mut mesh = MeshInfo::from(self);
         mesh.indices.clear();
-        let vertex_count = VERTS as u16;
+        let vertex_count = VERTS as VertexIdx;
         let connection_indices = (0..vertex_count).flat_map(|v_idx| {
             let next_v_idx = (v_idx + 1) % vertex_count;
             let inset_v_idx = v_idx + vertex_count;
@@ -206,7 +369,7 @@ impl<const VERTS: usize, const TRIS: usize> Face<VERTS, TRIS> {
                 Tri([next_inset_v_idx, next_v_idx, v_idx]),
                 Tri([v_idx, inset_v_idx, next_inset_v_idx]),
             ];
-            if scale < 0.0 {
+            if flip[usize::from(v_idx)] {
                 a.flip();
                 b.flip();
             }
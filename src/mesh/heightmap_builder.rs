@@ -79,6 +79,9 @@ pub struct HeightMapMeshBuilder<'l, 'm, HeightMap> {
     pub rotation: Option<Quat>,
     /// If set to `true`, the mesh will ignore [`HexLayout::origin`]
     pub center_aligned: bool,
+    /// If set to `true`, normals of vertices sharing the same position will be
+    /// averaged, otherwise each face keeps its flat normal
+    pub smooth_normals: bool,
     /// Specifies the height for side quads to be generated at the fringe
     /// of the `map` (Map edge and potential holes in sparse maps).
     ///
@@ -117,6 +120,7 @@ impl<'l, 'm, HeightMap: HexStore<f32>> HeightMapMeshBuilder<'l, 'm, HeightMap> {
             scale: None,
             rotation: None,
             center_aligned: false,
+            smooth_normals: false,
             fringe_heights: None,
             custom_caps_options: None,
             custom_sides_options: None,
@@ -325,6 +329,17 @@ impl<'l, 'm, HeightMap: HexStore<f32>> HeightMapMeshBuilder<'l, 'm, HeightMap> {
         self
     }
 
+    #[must_use]
+    #[inline]
+    /// Specify whether the mesh should have *smooth* normals, averaged between
+    /// faces sharing a vertex, or *flat* per face normals (default).
+    ///
+    /// See [`MeshInfo::with_smooth_normals`]
+    pub const fn with_smooth_normals(mut self, smooth: bool) -> Self {
+        self.smooth_normals = smooth;
+        self
+    }
+
     /// Comsumes the builder to return the computed mesh data
     pub fn build(self) -> MeshInfo {
         // We create the final mesh
@@ -338,6 +353,7 @@ impl<'l, 'm, HeightMap: HexStore<f32>> HeightMapMeshBuilder<'l, 'm, HeightMap> {
             ],
         };
 
+        let mirrored = self.layout.is_mirrored();
         for (hex, &height) in self.map.iter() {
             if let Some(opts) = self.top_face_options {
                 // Maybe custom options
@@ -358,7 +374,10 @@ impl<'l, 'm, HeightMap: HexStore<f32>> HeightMapMeshBuilder<'l, 'm, HeightMap> {
                 mesh.merge_with(plane.build());
             }
             if let Some(side_opts) = self.side_options {
-                let corners = self.layout.hex_edge_corners(hex);
+                // Quads are built center aligned for their normals to face outwards
+                let corners = self.layout.center_aligned_edge_corners();
+                let center = self.layout.hex_to_center_aligned_world_pos(hex);
+                let center = Vec3::new(center.x, 0.0, center.y);
                 for dir in EdgeDirection::ALL_DIRECTIONS {
                     let neighbor = hex + dir;
                     let opt_height = self.map.get(hex + dir).copied();
@@ -378,11 +397,21 @@ impl<'l, 'm, HeightMap: HexStore<f32>> HeightMapMeshBuilder<'l, 'm, HeightMap> {
                     if neighbor_height >= height {
                         continue;
                     }
-                    let quad = Quad::new_bounded([a, b], neighbor_height, height, [min, max]);
-                    mesh.merge_with(quad.apply_options(&side_opts));
+                    let mut quad = Quad::new_bounded([a, b], neighbor_height, height, [min, max]);
+                    let normal = self.layout.edge_normal(dir);
+                    quad.normals = [Vec3::new(normal.x, 0.0, normal.y); 4];
+                    let mut quad = quad.apply_options(&side_opts).with_offset(center);
+                    // Mirrored layouts have corners in reverse order
+                    if mirrored {
+                        quad.flip_winding();
+                    }
+                    mesh.merge_with(quad);
                 }
             }
         }
+        if self.smooth_normals {
+            mesh = mesh.with_smooth_normals();
+        }
         // **S** - We apply optional scale
         if let Some(scale) = self.scale {
             mesh = mesh.with_scale(scale);
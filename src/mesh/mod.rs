@@ -1,22 +1,44 @@
 pub(crate) mod column_builder;
+mod coplanar;
+mod decimation;
 /// Utility module for mesh construction
 pub mod face;
 pub(crate) mod heightmap_builder;
+pub(crate) mod outline_builder;
 pub(crate) mod plane_builder;
 #[cfg(test)]
 mod tests;
 mod uv_mapping;
 
-pub use column_builder::ColumnMeshBuilder;
+pub use column_builder::{ColumnMeshBuilder, ColumnMeshParts};
 pub use heightmap_builder::HeightMapMeshBuilder;
+pub use outline_builder::OutlineMeshBuilder;
 pub use plane_builder::PlaneMeshBuilder;
 pub use uv_mapping::{Rect, UVOptions};
 
-use glam::{Quat, Vec2, Vec3};
+use glam::{IVec3, Quat, Vec2, Vec3};
+use std::{collections::HashMap, fmt};
 
 use crate::{Hex, HexLayout};
 
 pub(crate) const BASE_FACING: Vec3 = Vec3::Y;
+/// Inverse of the distance under which two vertex positions are considered
+/// identical
+const WELD_PRECISION: f32 = 10_000.0;
+
+/// Quantizes a vertex position to be resilient to floating point errors when
+/// welding vertices
+fn weld_key(v: Vec3) -> IVec3 {
+    (v * WELD_PRECISION).round().as_ivec3()
+}
+
+/// Vertex index type of [`MeshInfo`] triangles.
+///
+/// Mesh builders always emit [`MeshInfo`] with [`MeshIndex`] indices. Use
+/// [`MeshInfo::into_u32_indices`] or [`MeshInfo32::merge_all`] after building
+/// to get a [`MeshInfo32`] for meshes with more than [`MeshIndex::MAX`]
+/// vertices, like large merged meshes.
+pub type MeshIndex = u16;
 
 /// Insetting options for [`PlaneMeshBuilder`] and [`ColumnMeshBuilder`]
 /// used to create an insetted face on either hexagonal planes or quads
@@ -26,7 +48,8 @@ pub(crate) const BASE_FACING: Vec3 = Vec3::Y;
 pub struct InsetOptions {
     /// If set to `true` the original downscaled face will be kept
     pub keep_inner_face: bool,
-    /// Scale factor
+    /// Scale factor, ignored for [`InsetScaleMode::PerVertex`] which has its
+    /// own scale for each vertex
     pub scale: f32,
     /// Inset mode
     pub mode: InsetScaleMode,
@@ -44,6 +67,13 @@ pub enum InsetScaleMode {
     /// Each inset vertex position will be at a proportional scale of the
     /// original one relative to its smallest edge
     SmallestEdge,
+    /// Like [`Self::SmallestEdge`] but with a custom scale for each vertex,
+    /// in hexagon corner order, allowing uneven bevels. [`InsetOptions::scale`]
+    /// is ignored.
+    ///
+    /// Faces with less vertices, like column side quads, use the first
+    /// scales. See [`Face::inset_per_vertex`](face::Face::inset_per_vertex)
+    PerVertex([f32; 6]),
 }
 
 /// 3d face uv and insetting options
@@ -95,7 +125,8 @@ impl FaceOptions {
 ///
 /// ## Merging
 ///
-/// `MeshInfo` can be merged with other meshes using `Self::merge_with`.
+/// `MeshInfo` can be merged with other meshes using `Self::merge_with`, or
+/// many meshes at once using `Self::merge_all`.
 /// Don't forget to offset the meshes in the mesh builder using:
 /// * [`ColumnMeshBuilder::at`]
 /// * [`PlaneMeshBuilder::at`]
@@ -111,10 +142,134 @@ pub struct MeshInfo {
     /// UV coordinates of each vertex (`Vertex_Uv` attribute)
     pub uvs: Vec<Vec2>,
     /// Vertex indices for triangles
-    pub indices: Vec<u16>,
+    pub indices: Vec<MeshIndex>,
+}
+
+/// [`MeshInfo`] with [`u32`] vertex indices, for meshes with more than
+/// [`MeshIndex::MAX`] vertices like large merged meshes.
+///
+/// Mesh builders don't emit `u32` indices directly, build it from
+/// [`MeshInfo::into_u32_indices`] or [`Self::merge_all`] after building.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let layout = HexLayout::default();
+/// let mesh = MeshInfo32::merge_all(
+///     Hex::ZERO
+///         .range(50)
+///         .map(|h| ColumnMeshBuilder::new(&layout, 1.0).at(h).build()),
+/// );
+/// assert!(mesh.vertices.len() > usize::from(u16::MAX));
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct MeshInfo32 {
+    /// All vertices positions information (`Vertex_Position` attribute)
+    pub vertices: Vec<Vec3>,
+    /// Normals for each vertex (You might need to swap `y` and `z`)
+    /// (`Vertex_Normal` attribute)
+    pub normals: Vec<Vec3>,
+    /// UV coordinates of each vertex (`Vertex_Uv` attribute)
+    pub uvs: Vec<Vec2>,
+    /// Vertex indices for triangles
+    pub indices: Vec<u32>,
+}
+
+impl MeshInfo32 {
+    /// Merges `rhs` into `self`.
+    /// All vertices, normals and uvs are appended to `self` and indices are
+    /// offsetted to maintain triangle data.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if there are more vertices than [`u32::MAX`]
+    pub fn merge_with(&mut self, rhs: MeshInfo) {
+        let indices_offset =
+            u32::try_from(self.vertices.len()).expect("MeshInfo32 has too many vertices");
+        self.vertices.extend(rhs.vertices);
+        self.normals.extend(rhs.normals);
+        self.uvs.extend(rhs.uvs);
+        self.indices.extend(
+            rhs.indices
+                .into_iter()
+                .map(|i| u32::from(i) + indices_offset),
+        );
+    }
+
+    /// Merges all `meshes` into a single mesh, like successive calls to
+    /// [`Self::merge_with`] but allocating the buffers only once.
+    ///
+    /// Unlike [`MeshInfo::merge_all`] the merged mesh can have more than
+    /// [`u16::MAX`] vertices.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the merged mesh has more vertices than [`u32::MAX`]
+    #[must_use]
+    pub fn merge_all(meshes: impl IntoIterator<Item = MeshInfo>) -> Self {
+        let meshes: Vec<MeshInfo> = meshes.into_iter().collect();
+        let [vertex_count, index_count] = meshes.iter().fold([0, 0], |[v, i], m| {
+            [v + m.vertices.len(), i + m.indices.len()]
+        });
+        let mut res = Self {
+            vertices: Vec::with_capacity(vertex_count),
+            normals: Vec::with_capacity(vertex_count),
+            uvs: Vec::with_capacity(vertex_count),
+            indices: Vec::with_capacity(index_count),
+        };
+        for mesh in meshes {
+            res.merge_with(mesh);
+        }
+        res
+    }
+}
+
+impl From<MeshInfo> for MeshInfo32 {
+    fn from(mesh: MeshInfo) -> Self {
+        mesh.into_u32_indices()
+    }
+}
+
+/// Error returned by [`MeshInfo::try_merge`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeshMergeError {
+    /// One of the meshes doesn't have as many normals and uvs as vertices
+    MismatchedAttributes,
+    /// The merged mesh would have more vertices than [`MeshIndex::MAX`]
+    TooManyVertices,
+}
+
+impl fmt::Display for MeshMergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MismatchedAttributes => {
+                write!(f, "mesh vertices, normals and uvs counts don't match")
+            }
+            Self::TooManyVertices => {
+                write!(f, "merged mesh would exceed {} vertices", MeshIndex::MAX)
+            }
+        }
+    }
 }
 
+impl std::error::Error for MeshMergeError {}
+
 impl MeshInfo {
+    /// Converts the mesh into a [`MeshInfo32`] with [`u32`] indices, which can
+    /// then be merged past [`u16::MAX`] vertices
+    #[must_use]
+    pub fn into_u32_indices(self) -> MeshInfo32 {
+        MeshInfo32 {
+            vertices: self.vertices,
+            normals: self.normals,
+            uvs: self.uvs,
+            indices: self.indices.into_iter().map(u32::from).collect(),
+        }
+    }
+
     /// Returns a new [`MeshInfo`] but with vertex positions and normals rotated
     #[inline]
     #[must_use]
@@ -152,6 +307,127 @@ impl MeshInfo {
         self
     }
 
+    /// Returns a new [`MeshInfo`] with *triplanar* uvs: each vertex uv is its
+    /// position projected on the plane most perpendicular to its normal,
+    /// multiplied by `scale`.
+    ///
+    /// * Vertices facing the `Y` axis get their `XZ` coordinates
+    /// * Vertices facing the `X` axis get their `ZY` coordinates
+    /// * Vertices facing the `Z` axis get their `XY` coordinates
+    ///
+    /// Textures are then mapped in world space (if applied on world space
+    /// positions), without any seam between adjacent meshes, which is useful
+    /// for terrain cliffs. The shader doesn't need to reconstruct positions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let mesh = PlaneMeshBuilder::new(&layout)
+    ///     .at(hex(2, 1))
+    ///     .build()
+    ///     .with_triplanar_uvs(0.5);
+    /// for (v, uv) in mesh.vertices.iter().zip(&mesh.uvs) {
+    ///     assert_eq!(*uv, Vec2::new(v.x, v.z) * 0.5);
+    /// }
+    /// ```
+    #[must_use]
+    pub fn with_triplanar_uvs(mut self, scale: f32) -> Self {
+        for ((uv, vertex), normal) in self.uvs.iter_mut().zip(&self.vertices).zip(&self.normals) {
+            let n = normal.abs();
+            *uv = if n.y >= n.x && n.y >= n.z {
+                Vec2::new(vertex.x, vertex.z)
+            } else if n.x >= n.z {
+                Vec2::new(vertex.z, vertex.y)
+            } else {
+                Vec2::new(vertex.x, vertex.y)
+            } * scale;
+        }
+        self
+    }
+
+    /// Returns a new [`MeshInfo`] with *smooth* normals: vertices sharing the
+    /// same position get the average of their normals.
+    ///
+    /// Vertices are not merged, so the UV mapping is preserved
+    #[must_use]
+    pub fn with_smooth_normals(mut self) -> Self {
+        let sums = self.welded_normal_sums();
+        for (vertex, normal) in self.vertices.iter().zip(&mut self.normals) {
+            *normal = sums[&weld_key(*vertex)].try_normalize().unwrap_or(*normal);
+        }
+        self
+    }
+
+    /// Sums the normals of the vertices sharing the same position, indexed by
+    /// [`weld_key`]
+    fn welded_normal_sums(&self) -> HashMap<IVec3, Vec3> {
+        let mut sums: HashMap<IVec3, Vec3> = HashMap::with_capacity(self.vertices.len());
+        for (vertex, normal) in self.vertices.iter().zip(&self.normals) {
+            *sums.entry(weld_key(*vertex)).or_default() += *normal;
+        }
+        sums
+    }
+
+    /// Displaces every vertex along its normal by the distance returned by
+    /// `f` for the vertex position, for example a noise function for organic
+    /// terrain.
+    ///
+    /// Vertices sharing the same position are displaced along their averaged
+    /// normal, so flat shaded meshes don't crack open.
+    ///
+    /// Normals are left untouched, call [`Self::recompute_normals`] to match
+    /// the displaced geometry.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let mut mesh = ColumnMeshBuilder::new(&layout, 2.0)
+    ///     .with_subdivisions(4)
+    ///     .build();
+    /// mesh.displace(|p| (p.x * 3.0).sin() * 0.1);
+    /// mesh.recompute_normals();
+    /// ```
+    pub fn displace(&mut self, f: impl Fn(Vec3) -> f32) {
+        let sums = self.welded_normal_sums();
+        for (vertex, normal) in self.vertices.iter_mut().zip(&self.normals) {
+            let direction = sums[&weld_key(*vertex)].try_normalize().unwrap_or(*normal);
+            *vertex += direction * f(*vertex);
+        }
+    }
+
+    /// Recomputes the vertex normals from the triangles, each vertex getting
+    /// the area weighted average of the normals of the triangles using it.
+    ///
+    /// Vertices shared by triangles get *smooth* normals while split vertices
+    /// keep *flat* normals. Vertices used by no triangle keep their normal.
+    pub fn recompute_normals(&mut self) {
+        let mut sums = vec![Vec3::ZERO; self.vertices.len()];
+        for tri in self.indices.chunks_exact(3) {
+            let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| i as usize);
+            let [pa, pb, pc] = [a, b, c].map(|i| self.vertices[i]);
+            // Cross product length is twice the triangle area
+            let normal = (pb - pa).cross(pc - pa);
+            for i in [a, b, c] {
+                sums[i] += normal;
+            }
+        }
+        for (normal, sum) in self.normals.iter_mut().zip(sums) {
+            *normal = sum.try_normalize().unwrap_or(*normal);
+        }
+    }
+
+    /// Flips the order of every triangle indices, making them face the other
+    /// way
+    pub(crate) fn flip_winding(&mut self) {
+        self.indices
+            .chunks_exact_mut(3)
+            .for_each(|tri| tri.swap(0, 2));
+    }
+
     /// Computes the centroid of the mesh vertices
     #[inline]
     #[must_use]
@@ -177,13 +453,14 @@ impl MeshInfo {
     /// # Note
     ///
     /// This method doesn't merge vertices or tries to remove invisible faces.
+    /// Use [`Self::try_merge`] to check the meshes consistency first.
     ///
     /// # Panics
     ///
-    /// Will panic if there are more vertices than [`u16::MAX`]
+    /// Will panic if there are more vertices than [`MeshIndex::MAX`]
     pub fn merge_with(&mut self, rhs: Self) {
         let indices_offset =
-            u16::try_from(self.vertices.len()).expect("MeshInfo has too many vertices");
+            MeshIndex::try_from(self.vertices.len()).expect("MeshInfo has too many vertices");
         self.vertices.extend(rhs.vertices);
         self.normals.extend(rhs.normals);
         self.uvs.extend(rhs.uvs);
@@ -191,6 +468,83 @@ impl MeshInfo {
             .extend(rhs.indices.into_iter().map(|i| i + indices_offset));
     }
 
+    /// Merges `rhs` into `self`, like [`Self::merge_with`] but checking both
+    /// meshes first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error and leaves `self` untouched if:
+    /// - Either mesh doesn't have the same amount of vertices, normals and uvs,
+    ///   as merging them would misalign the attribute buffers
+    /// - The merged mesh would have more vertices than [`MeshIndex::MAX`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let mut mesh = PlaneMeshBuilder::new(&layout).build();
+    /// let mut other = PlaneMeshBuilder::new(&layout).at(hex(1, 0)).build();
+    /// other.uvs.clear();
+    /// assert_eq!(
+    ///     mesh.try_merge(other),
+    ///     Err(MeshMergeError::MismatchedAttributes)
+    /// );
+    /// ```
+    pub fn try_merge(&mut self, rhs: Self) -> Result<(), MeshMergeError> {
+        let consistent =
+            |m: &Self| m.normals.len() == m.vertices.len() && m.uvs.len() == m.vertices.len();
+        if !consistent(self) || !consistent(&rhs) {
+            return Err(MeshMergeError::MismatchedAttributes);
+        }
+        if self.vertices.len() + rhs.vertices.len() > MeshIndex::MAX as usize + 1 {
+            return Err(MeshMergeError::TooManyVertices);
+        }
+        self.merge_with(rhs);
+        Ok(())
+    }
+
+    /// Merges all `meshes` into a single mesh, like successive calls to
+    /// [`Self::merge_with`] but allocating the buffers only once.
+    ///
+    /// Useful to batch many tile meshes into a single one.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the merged mesh has more vertices than [`MeshIndex::MAX`],
+    /// use [`MeshInfo32::merge_all`] for larger meshes
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let tile = ColumnMeshBuilder::new(&layout, 1.0).build();
+    /// let mesh = MeshInfo::merge_all(
+    ///     Hex::ZERO
+    ///         .range(5)
+    ///         .map(|h| ColumnMeshBuilder::new(&layout, 1.0).at(h).build()),
+    /// );
+    /// assert_eq!(mesh.vertices.len(), 91 * tile.vertices.len());
+    /// ```
+    #[must_use]
+    pub fn merge_all(meshes: impl IntoIterator<Item = Self>) -> Self {
+        let meshes: Vec<Self> = meshes.into_iter().collect();
+        let [vertex_count, index_count] = meshes.iter().fold([0, 0], |[v, i], m| {
+            [v + m.vertices.len(), i + m.indices.len()]
+        });
+        let mut res = Self {
+            vertices: Vec::with_capacity(vertex_count),
+            normals: Vec::with_capacity(vertex_count),
+            uvs: Vec::with_capacity(vertex_count),
+            indices: Vec::with_capacity(index_count),
+        };
+        for mesh in meshes {
+            res.merge_with(mesh);
+        }
+        res
+    }
+
     /// Computes cheap mesh data for an hexagonal column facing `Vec3::Y`
     /// without the bottom face.
     ///
@@ -0,0 +1,315 @@
+use crate::{
+    ColumnMeshBuilder, EdgeDirection, Hex, HexLayout, MeshInfo, UVOptions, VertexDirection,
+    BASE_FACING,
+};
+use glam::{Mat2, Quat, Vec2, Vec3};
+use std::collections::HashSet;
+
+/// Default outline size factor, relative to the hexagon size
+const DEFAULT_OUTLINE_FACTOR: f32 = 1.1;
+
+/// Builder struct to customize hex outline mesh generation.
+///
+/// The outline is a flat ring around the hexagon, between its edges and the
+/// edges of a *larger* hexagon. By default the larger hexagon is 10% bigger,
+/// use [`Self::with_thickness`] for a constant thickness in world units.
+///
+/// The mesh will be anchored at the center of the hexagon, use offsets to
+/// cutomize anchor/pivot position.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+///
+/// let layout = HexLayout::default();
+/// let mesh = OutlineMeshBuilder::new(&layout)
+///     .at(hex(2, 3))
+///     .with_thickness(0.05)
+///     .build();
+/// ```
+///
+/// # Note
+///
+/// Transform operations (Scale, Rotate, Translate) through the methods
+///
+/// - Scale: [`Self::with_scale`]
+/// - Rotate: [`Self::with_rotation`], [`Self::facing`]
+/// - Translate: [`Self::with_offset`], [`Self::at`]
+///
+/// Are executed in that order, or **SRT**
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub struct OutlineMeshBuilder<'l> {
+    /// The hexagonal layout, used to compute vertex positions
+    pub layout: &'l HexLayout,
+    /// Custom hex position, will apply an offset if not [`Hex::ZERO`]
+    pub pos: Hex,
+    /// Optional custom offset for the mesh vertex positions
+    pub offset: Option<Vec3>,
+    /// Optional custom scale factor for the mesh vertex positions
+    pub scale: Option<Vec3>,
+    /// Optional custom rotation, useful to have the mesh already
+    /// rotated
+    ///
+    /// By default the mesh is *facing* up (**Y** axis)
+    pub rotation: Option<Quat>,
+    /// Optional outline thickness in world units, added to the layout
+    /// `scale` on each axis. If not set the outline is 10% of the hexagon
+    /// size
+    pub thickness: Option<f32>,
+    /// UV mapping options
+    pub uv_options: UVOptions,
+    /// If set to `true`, the mesh will ignore [`HexLayout::origin`]
+    pub center_aligned: bool,
+}
+
+impl<'l> OutlineMeshBuilder<'l> {
+    /// Setup a new builder using the given `layout`
+    #[must_use]
+    pub const fn new(layout: &'l HexLayout) -> Self {
+        Self {
+            layout,
+            pos: Hex::ZERO,
+            rotation: None,
+            offset: None,
+            scale: None,
+            thickness: None,
+            uv_options: UVOptions::new(),
+            center_aligned: false,
+        }
+    }
+
+    /// Specifies a custom `pos`, which will apply an offset to the whole mesh.
+    #[must_use]
+    pub const fn at(mut self, pos: Hex) -> Self {
+        self.pos = pos;
+        self
+    }
+
+    /// Specify a custom *facing* direction for the mesh, by default the
+    /// outline is horizontal (facing up)
+    ///
+    /// # Panics
+    ///
+    /// Will panic if `facing` is zero length
+    #[must_use]
+    pub fn facing(mut self, facing: Vec3) -> Self {
+        self.rotation = Some(Quat::from_rotation_arc(BASE_FACING, facing.normalize()));
+        self
+    }
+
+    /// Specify a custom rotation for the whole mesh
+    #[must_use]
+    pub const fn with_rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = Some(rotation);
+        self
+    }
+
+    /// Specify a custom offset for the whole mesh
+    #[must_use]
+    pub const fn with_offset(mut self, offset: Vec3) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Specify a custom scale factor for the whole mesh
+    #[must_use]
+    pub const fn with_scale(mut self, scale: Vec3) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Specify the outline thickness in world units.
+    ///
+    /// The outer hexagon uses the layout `scale` increased by `thickness` on
+    /// each axis, so the outline keeps the same thickness for any hexagon
+    /// size.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default().with_hex_size(50.0);
+    /// // A 2 pixels wide border
+    /// let mesh = OutlineMeshBuilder::new(&layout).with_thickness(2.0).build();
+    /// ```
+    #[must_use]
+    pub const fn with_thickness(mut self, thickness: f32) -> Self {
+        self.thickness = Some(thickness);
+        self
+    }
+
+    /// Specify custom UV mapping options
+    #[must_use]
+    pub const fn with_uv_options(mut self, uv_options: UVOptions) -> Self {
+        self.uv_options = uv_options;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Ignores the [`HexLayout::origin`] offset, generating a mesh centered
+    /// around `(0.0, 0.0)`.
+    pub const fn center_aligned(mut self) -> Self {
+        self.center_aligned = true;
+        self
+    }
+
+    /// Computes the layout of the outline outer edges
+    fn outer_layout(&self) -> HexLayout {
+        let scale = self.layout.scale;
+        let scale = self.thickness.map_or(scale * DEFAULT_OUTLINE_FACTOR, |t| {
+            scale + scale.signum() * t
+        });
+        self.layout.clone().with_scale(scale)
+    }
+
+    /// Comsumes the builder to return the computed mesh data
+    #[must_use]
+    pub fn build(self) -> MeshInfo {
+        let inner = self.layout.center_aligned_edge_corners();
+        let outer = self.outer_layout().center_aligned_edge_corners();
+        let mesh = ColumnMeshBuilder::rim(
+            &outer,
+            &inner,
+            &[[0.0; 2]; 6],
+            0.0,
+            &self.uv_options,
+            self.layout.is_mirrored(),
+        );
+        self.transformed(mesh, self.pos)
+    }
+
+    /// Applies the builder transforms to `mesh`, placing it at `hex`
+    fn transformed(&self, mut mesh: MeshInfo, hex: Hex) -> MeshInfo {
+        // We store the offset to match the `hex`
+        let pos = if self.center_aligned {
+            self.layout.hex_to_center_aligned_world_pos(hex)
+        } else {
+            self.layout.hex_to_world_pos(hex)
+        };
+        let mut offset = Vec3::new(pos.x, 0.0, pos.y);
+        // **S** - We apply optional scale
+        if let Some(scale) = self.scale {
+            mesh = mesh.with_scale(scale);
+        }
+        // **R** - We rotate the mesh to face the given direction
+        if let Some(rotation) = self.rotation {
+            mesh = mesh.rotated(rotation);
+        }
+        // **T** - We offset the vertex positions after scaling and rotating
+        if let Some(custom_offset) = self.offset {
+            offset += custom_offset;
+        }
+        mesh.with_offset(offset)
+    }
+
+    /// Computes the outer corners of the `hex` outline in the `hexes` region.
+    ///
+    /// Where the region boundary turns inward, the outer corner is mitered
+    /// between the two boundary edges so that the outlines of adjacent hexes
+    /// meet without overlapping.
+    fn region_outer_corners(&self, hex: Hex, hexes: &HashSet<Hex>) -> [Vec2; 6] {
+        let inner = self.layout.center_aligned_hex_corners();
+        let outer = self.outer_layout().center_aligned_hex_corners();
+        // Outline thickness along the normal of every edge
+        let thickness = |dir: EdgeDirection| {
+            let [a, b] = dir.vertex_directions().map(|v| {
+                let i = usize::from(v.index());
+                outer[i] - inner[i]
+            });
+            self.layout.edge_normal(dir).dot(a + b) / 2.0
+        };
+        let miter = |vertex: VertexDirection, a: EdgeDirection, b: EdgeDirection| {
+            let normals = Mat2::from_cols(self.layout.edge_normal(a), self.layout.edge_normal(b));
+            let offset = normals.transpose().inverse() * Vec2::new(thickness(a), thickness(b));
+            inner[usize::from(vertex.index())] + offset
+        };
+        VertexDirection::ALL_DIRECTIONS.map(|vertex| {
+            let [ccw, cw] = vertex.edge_directions();
+            let outside = [ccw, cw].map(|dir| !hexes.contains(&hex.neighbor(dir)));
+            // For an inward corner the boundary continues on the edge between
+            // the neighbor in the region and the neighbor outside of it
+            match outside {
+                [true, false] => miter(vertex, ccw, ccw.counter_clockwise()),
+                [false, true] => miter(vertex, cw, cw.clockwise()),
+                _ => outer[usize::from(vertex.index())],
+            }
+        })
+    }
+
+    /// Computes a single outline mesh tracing the boundary of the `hexes`
+    /// region, with default options.
+    ///
+    /// See [`Self::build_for_region`] to customize the outline
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the merged mesh has more vertices than
+    /// [`MeshIndex::MAX`](super::MeshIndex::MAX)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// # use std::collections::HashSet;
+    /// let layout = HexLayout::default();
+    /// let region: HashSet<_> = Hex::ZERO.range(2).collect();
+    /// let mesh = OutlineMeshBuilder::for_region(&layout, &region);
+    /// // The 6 corner hexes have 3 border edges, the 6 others have 2
+    /// assert_eq!(mesh.vertices.len(), (6 * 3 + 6 * 2) * 4);
+    /// ```
+    #[must_use]
+    pub fn for_region(layout: &'l HexLayout, hexes: &HashSet<Hex>) -> MeshInfo {
+        Self::new(layout).build_for_region(hexes)
+    }
+
+    /// Comsumes the builder to return a single outline mesh tracing the
+    /// boundary of the `hexes` region.
+    ///
+    /// Only the edges between a hex of the region and a hex outside of it
+    /// are outlined, so holes in the region are outlined as well. Where the
+    /// boundary turns inward, the outlines of adjacent hexes are mitered to
+    /// share their outer corner. Every edge gets the builder options, as if
+    /// built with [`Self::at`]. The builder `pos` is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the merged mesh has more vertices than
+    /// [`MeshIndex::MAX`](super::MeshIndex::MAX)
+    #[must_use]
+    pub fn build_for_region(self, hexes: &HashSet<Hex>) -> MeshInfo {
+        let inner = self.layout.center_aligned_edge_corners();
+        MeshInfo::merge_all(hexes.iter().flat_map(|hex| {
+            let corners = self.region_outer_corners(*hex, hexes);
+            let outer = EdgeDirection::ALL_DIRECTIONS.map(|dir| {
+                dir.vertex_directions()
+                    .map(|v| corners[usize::from(v.index())])
+            });
+            let rim = ColumnMeshBuilder::rim(
+                &outer,
+                &inner,
+                &[[0.0; 2]; 6],
+                0.0,
+                &self.uv_options,
+                self.layout.is_mirrored(),
+            );
+            let rim = self.transformed(rim, *hex);
+            // Every edge quad has 4 vertices and 6 indices
+            EdgeDirection::ALL_DIRECTIONS
+                .into_iter()
+                .filter(|dir| !hexes.contains(&hex.neighbor(*dir)))
+                .map(move |dir| {
+                    let [v, i] = [4, 6].map(|len| len * usize::from(dir.index()));
+                    let start = rim.indices[i..i + 6].iter().min().copied().unwrap_or(0);
+                    MeshInfo {
+                        vertices: rim.vertices[v..v + 4].to_vec(),
+                        normals: rim.normals[v..v + 4].to_vec(),
+                        uvs: rim.uvs[v..v + 4].to_vec(),
+                        indices: rim.indices[i..i + 6].iter().map(|i| i - start).collect(),
+                    }
+                })
+        }))
+    }
+}
@@ -37,6 +37,12 @@ pub struct PlaneMeshBuilder<'l> {
     pub face_options: FaceOptions,
     /// If set to `true`, the mesh will ignore [`HexLayout::origin`]
     pub center_aligned: bool,
+    /// If set to `true`, normals of vertices sharing the same position will be
+    /// averaged, otherwise each face keeps its flat normal
+    pub smooth_normals: bool,
+    /// Optional subdivision levels of the hexagon face, see
+    /// [`Hexagon::subdivided`]
+    pub subdivisions: Option<u32>,
 }
 
 impl<'l> PlaneMeshBuilder<'l> {
@@ -51,6 +57,8 @@ impl<'l> PlaneMeshBuilder<'l> {
             scale: None,
             face_options: FaceOptions::new(),
             center_aligned: false,
+            smooth_normals: false,
+            subdivisions: None,
         }
     }
 
@@ -130,7 +138,54 @@ impl<'l> PlaneMeshBuilder<'l> {
         self
     }
 
+    #[must_use]
+    #[inline]
+    /// Specify whether the mesh should have *smooth* normals, averaged between
+    /// faces sharing a vertex, or *flat* per face normals (default).
+    ///
+    /// See [`MeshInfo::with_smooth_normals`]
+    pub const fn with_smooth_normals(mut self, smooth: bool) -> Self {
+        self.smooth_normals = smooth;
+        self
+    }
+
+    #[must_use]
+    #[inline]
+    /// Subdivides the hexagon face into a triangular lattice, each of the
+    /// `levels` splitting every triangle in 4. Useful for vertex displacement,
+    /// see [`Hexagon::subdivided`].
+    ///
+    /// A `levels` value of 0 keeps the default face.
+    ///
+    /// # Note
+    ///
+    /// The inset options are ignored for subdivided faces
+    ///
+    /// # Panics
+    ///
+    /// The maximum `levels` value is 7. Higher values exceed the
+    /// [`MeshIndex::MAX`](super::MeshIndex::MAX) vertices and make
+    /// [`Self::build`] panic.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let mesh = PlaneMeshBuilder::new(&layout).with_subdivisions(3).build();
+    /// assert_eq!(mesh.indices.len(), 6 * 8 * 8 * 3);
+    /// ```
+    pub const fn with_subdivisions(mut self, levels: u32) -> Self {
+        self.subdivisions = Some(levels);
+        self
+    }
+
     /// Comsumes the builder to return the computed mesh data
+    ///
+    /// # Panics
+    ///
+    /// Will panic with more than 7 subdivision levels, see
+    /// [`Self::with_subdivisions`]
     #[must_use]
     pub fn build(self) -> MeshInfo {
         // We compute the mesh at the origin and no offset to allow scaling
@@ -142,12 +197,19 @@ impl<'l> PlaneMeshBuilder<'l> {
             self.layout.hex_to_world_pos(self.pos)
         };
         let mut offset = Vec3::new(pos.x, 0.0, pos.y);
-        // We apply optional insetting
-        let mut mesh = if let Some(inset) = self.face_options.insetting {
-            face.inset(inset.mode, inset.scale, inset.keep_inner_face)
-        } else {
-            face.into()
+        // We apply optional subdivisions or insetting
+        let mut mesh = match (self.subdivisions, self.face_options.insetting) {
+            (Some(levels), _) if levels > 0 => face.subdivided(levels),
+            (_, Some(inset)) => face.inset(inset.mode, inset.scale, inset.keep_inner_face),
+            _ => face.into(),
         };
+        // Mirrored layouts have corners in reverse order
+        if self.layout.is_mirrored() {
+            mesh.flip_winding();
+        }
+        if self.smooth_normals {
+            mesh = mesh.with_smooth_normals();
+        }
         // **S** - We apply optional scale
         if let Some(scale) = self.scale {
             mesh = mesh.with_scale(scale);
@@ -164,4 +226,59 @@ impl<'l> PlaneMeshBuilder<'l> {
         self.face_options.uv.alter_uvs(&mut mesh.uvs);
         mesh
     }
+
+    /// Computes a single plane mesh with a hexagon face for each of the given
+    /// `hexes`, with default options.
+    ///
+    /// See [`Self::build_for_hexes`] to customize the faces
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the merged mesh has more vertices than
+    /// [`MeshIndex::MAX`](super::MeshIndex::MAX)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let mesh = PlaneMeshBuilder::for_hexes(&layout, Hex::ZERO.range(3));
+    /// assert_eq!(mesh.vertices.len(), 37 * 6);
+    /// ```
+    #[must_use]
+    pub fn for_hexes(layout: &'l HexLayout, hexes: impl IntoIterator<Item = Hex>) -> MeshInfo {
+        Self::new(layout).build_for_hexes(hexes)
+    }
+
+    /// Comsumes the builder to return a single mesh with a hexagon face for
+    /// each of the given `hexes`, sharing one vertex buffer.
+    ///
+    /// Every face gets the builder options, as if built with
+    /// [`Self::at`] and merged. The builder `pos` is ignored.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the merged mesh has more vertices than
+    /// [`MeshIndex::MAX`](super::MeshIndex::MAX)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::*;
+    /// let layout = HexLayout::default();
+    /// let region = [hex(0, 0), hex(1, 0), hex(1, -1)];
+    /// let mesh = PlaneMeshBuilder::new(&layout)
+    ///     .with_uv_options(UVOptions::new().with_scale_factor(Vec2::splat(0.5)))
+    ///     .build_for_hexes(region);
+    /// ```
+    #[must_use]
+    pub fn build_for_hexes(self, hexes: impl IntoIterator<Item = Hex>) -> MeshInfo {
+        let layout = self.layout;
+        // The face is computed once and offsetted for each hex
+        let base = self.at(Hex::ZERO).build();
+        MeshInfo::merge_all(hexes.into_iter().map(|hex| {
+            let pos = layout.hex_to_center_aligned_world_pos(hex);
+            base.clone().with_offset(Vec3::new(pos.x, 0.0, pos.y))
+        }))
+    }
 }
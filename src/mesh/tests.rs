@@ -1,4 +1,11 @@
-use crate::{ColumnMeshBuilder, HexLayout, MeshInfo, PlaneMeshBuilder};
+use crate::{
+    face::{Hexagon, Quad},
+    ColumnMeshBuilder, DirectionMask, EdgeDirection, FaceOptions, HeightMapMeshBuilder, Hex,
+    HexLayout, HexOrientation, InsetOptions, InsetScaleMode, MeshInfo, MeshInfo32, MeshMergeError,
+    OutlineMeshBuilder, PlaneMeshBuilder, Rect, UVOptions,
+};
+use glam::{Vec2, Vec3};
+use std::collections::{HashMap, HashSet};
 
 fn mesh_integrity(mesh: MeshInfo, expected_len: usize) {
     assert_eq!(mesh.vertices.len(), expected_len);
@@ -36,3 +43,902 @@ fn column_integrity() {
     let mesh = ColumnMeshBuilder::new(&layout, 10.0).build();
     mesh_integrity(mesh, 6 * 4 + 12);
 }
+
+#[test]
+fn smooth_normals() {
+    let layout = HexLayout::default();
+    let flat = ColumnMeshBuilder::new(&layout, 10.0)
+        .with_subdivisions(3)
+        .build();
+    let smooth = ColumnMeshBuilder::new(&layout, 10.0)
+        .with_subdivisions(3)
+        .with_smooth_normals(true)
+        .build();
+    assert_eq!(flat.vertices, smooth.vertices);
+    assert_eq!(flat.indices, smooth.indices);
+    assert_ne!(flat.normals, smooth.normals);
+    for (i, (v, n)) in smooth.vertices.iter().zip(&smooth.normals).enumerate() {
+        assert!(n.is_normalized());
+        // Vertices sharing a position share the same normal
+        for (v2, n2) in smooth.vertices.iter().zip(&smooth.normals).skip(i + 1) {
+            if v.distance(*v2) < 0.0001 {
+                assert!(n.abs_diff_eq(*n2, 0.0001));
+            }
+        }
+    }
+    mesh_integrity(smooth, 6 * 4 * 3 + 12);
+}
+
+/// Checks that every triangle winding matches its vertex normals
+fn winding_integrity(mesh: &MeshInfo) {
+    for tri in mesh.indices.chunks_exact(3) {
+        let [a, b, c] = [tri[0], tri[1], tri[2]].map(|i| mesh.vertices[i as usize]);
+        let face_normal = (b - a).cross(c - a);
+        for i in tri {
+            assert!(face_normal.dot(mesh.normals[*i as usize]) > 0.0);
+        }
+    }
+}
+
+#[test]
+fn inverted_axis_winding() {
+    for orientation in [HexOrientation::Flat, HexOrientation::Pointy] {
+        for scale in [
+            Vec2::ONE,
+            Vec2::new(-1.0, 1.0),
+            Vec2::new(1.0, -1.0),
+            Vec2::NEG_ONE,
+        ] {
+            let layout = HexLayout::new(orientation).with_scale(scale);
+            let plane = PlaneMeshBuilder::new(&layout).build();
+            winding_integrity(&plane);
+            let column = ColumnMeshBuilder::new(&layout, 10.0).build();
+            winding_integrity(&column);
+            let inset = ColumnMeshBuilder::new(&layout, 10.0)
+                .with_sides_options(FaceOptions {
+                    insetting: Some(InsetOptions {
+                        keep_inner_face: true,
+                        scale: 0.2,
+                        mode: InsetScaleMode::Centroid,
+                    }),
+                    ..FaceOptions::new()
+                })
+                .build();
+            winding_integrity(&inset);
+            let tube = ColumnMeshBuilder::new(&layout, 10.0)
+                .with_wall_thickness(0.2)
+                .build();
+            winding_integrity(&tube);
+            let map: HashMap<Hex, f32> = Hex::ZERO
+                .range(2)
+                .map(|h| {
+                    let height = match h.ulength() {
+                        0 => 3.0,
+                        1 => 2.0,
+                        _ => 1.0,
+                    };
+                    (h, height)
+                })
+                .collect();
+            let heightmap = HeightMapMeshBuilder::new(&layout, &map).build();
+            winding_integrity(&heightmap);
+        }
+    }
+}
+
+#[test]
+fn stretched_column_side_normals() {
+    let layout = HexLayout::pointy().with_scale(Vec2::new(3.0, 0.5));
+    let mesh = ColumnMeshBuilder::new(&layout, 10.0)
+        .without_top_face()
+        .without_bottom_face()
+        .build();
+    for quad in mesh.indices.chunks_exact(6) {
+        let [a, b, c] = [quad[0], quad[1], quad[2]].map(|i| mesh.vertices[i as usize]);
+        let face_normal = (b - a).cross(c - a).normalize();
+        for i in quad {
+            assert!(face_normal.abs_diff_eq(mesh.normals[*i as usize], 0.0001));
+        }
+    }
+}
+
+#[test]
+fn hollow_column() {
+    let layout = HexLayout::flat().with_scale(Vec2::new(2.0, 1.0));
+    let thickness = 0.3;
+    let mesh = ColumnMeshBuilder::new(&layout, 10.0)
+        .with_subdivisions(2)
+        .with_wall_thickness(thickness)
+        .build();
+    // Outer and inner walls plus two rims
+    mesh_integrity(mesh.clone(), 6 * 4 * 2 * 2 + 6 * 4 * 2);
+    // Inner walls are at `thickness` distance of the outer walls
+    let walls = &mesh.vertices[..6 * 4 * 2 * 2];
+    for (side, dir) in EdgeDirection::ALL_DIRECTIONS.into_iter().enumerate() {
+        let normal = layout.edge_normal(dir);
+        let normal = Vec3::new(normal.x, 0.0, normal.y);
+        let outer = walls[side * 16];
+        for inner in &walls[side * 16 + 8..side * 16 + 16] {
+            assert!(((outer - *inner).dot(normal) - thickness).abs() < 0.0001);
+        }
+    }
+    let open = ColumnMeshBuilder::new(&layout, 10.0)
+        .with_wall_thickness(thickness)
+        .without_top_face()
+        .without_bottom_face()
+        .build();
+    mesh_integrity(open, 6 * 4 * 2);
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn decimation() {
+    let layout = HexLayout::default();
+    let map: HashMap<Hex, f32> = Hex::ZERO
+        .range(8)
+        .map(|h| (h, f32::from(i16::try_from(h.x.abs() + h.y).unwrap_or(0))))
+        .collect();
+    let mesh = HeightMapMeshBuilder::new(&layout, &map).build();
+    let triangle_count = mesh.indices.len() / 3;
+    for ratio in [1.0, 0.8, 0.5, 0.1] {
+        let lod = mesh.decimate(ratio);
+        let lod_count = lod.indices.len() / 3;
+        assert!(lod_count as f32 <= triangle_count as f32 * ratio);
+        assert!(lod_count > 0);
+        assert_eq!(lod.vertices.len(), lod.normals.len());
+        assert_eq!(lod.vertices.len(), lod.uvs.len());
+        for normal in &lod.normals {
+            assert!(normal.is_normalized());
+        }
+        for tri in lod.indices.chunks_exact(3) {
+            assert!(tri.iter().all(|i| (*i as usize) < lod.vertices.len()));
+            assert!(tri[0] != tri[1] && tri[1] != tri[2] && tri[0] != tri[2]);
+        }
+    }
+    assert_eq!(mesh.decimate(1.0).indices, mesh.indices);
+    assert!(!mesh.decimate(0.0).indices.is_empty());
+}
+
+#[test]
+fn low_ratio_decimation() {
+    let layout = HexLayout::default();
+    let mesh = ColumnMeshBuilder::new(&layout, 2.0)
+        .with_subdivisions(3)
+        .build();
+    let triangle_count = mesh.indices.len() / 3;
+    assert_eq!(triangle_count, 44);
+    for ratio in [0.0, 0.05, 0.1, 0.25, 0.5] {
+        let lod = mesh.decimate(ratio);
+        let lod_count = lod.indices.len() / 3;
+        assert!(lod_count > 0);
+        assert!(lod_count < triangle_count);
+    }
+    // Coarsest non empty clustering
+    assert_eq!(mesh.decimate(0.05).indices.len() / 3, 4);
+    // Finest clustering under the target of 22 triangles
+    assert_eq!(mesh.decimate(0.5).indices.len() / 3, 12);
+}
+
+#[test]
+fn coplanar_merging() {
+    let area = |mesh: &MeshInfo| -> f32 {
+        mesh.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [t[0], t[1], t[2]].map(|i| mesh.vertices[i as usize]);
+                (b - a).cross(c - a).length() / 2.0
+            })
+            .sum()
+    };
+    let layout = HexLayout::pointy().with_hex_size(2.0);
+    let mut plain = MeshInfo::default();
+    for hex in Hex::ZERO.range(4) {
+        plain.merge_with(PlaneMeshBuilder::new(&layout).at(hex).build());
+    }
+    let mut merged = plain.clone();
+    merged.merge_coplanar(0.001);
+    assert!(merged.indices.len() < plain.indices.len() / 4);
+    assert!((area(&merged) - area(&plain)).abs() < 0.01);
+    winding_integrity(&merged);
+    for tri in merged.indices.chunks_exact(3) {
+        assert!(tri.iter().all(|i| (*i as usize) < merged.vertices.len()));
+    }
+    // Uneven terrain
+    let map: HashMap<Hex, f32> = Hex::ZERO
+        .range(6)
+        .map(|h| (h, if h.x > 1 { 2.0 } else { 0.0 }))
+        .collect();
+    let terrain = HeightMapMeshBuilder::new(&layout, &map).build();
+    let mut merged = terrain.clone();
+    merged.merge_coplanar(0.001);
+    assert!(merged.indices.len() < terrain.indices.len());
+    assert!((area(&merged) - area(&terrain)).abs() < 0.01);
+    winding_integrity(&merged);
+    mesh_integrity(merged.clone(), merged.vertices.len());
+    // Non flat meshes are untouched
+    let column = ColumnMeshBuilder::new(&layout, 1.0).build();
+    let mut merged = column.clone();
+    merged.merge_coplanar(0.001);
+    assert_eq!(merged.indices.len(), column.indices.len());
+}
+
+#[test]
+fn quad_custom_uvs() {
+    let sides = HexLayout::default().center_aligned_edge_corners()[0];
+    let quad = Quad::new(sides, 0.0, 3.0);
+    let uvs = [
+        Vec2::new(0.5, 0.0),
+        Vec2::new(0.5, 0.25),
+        Vec2::Y,
+        Vec2::ZERO,
+    ];
+    let custom = Quad::new_with_uvs(sides, 0.0, 3.0, uvs);
+    assert_eq!(quad.positions, custom.positions);
+    assert_eq!(quad.normals, custom.normals);
+    assert_eq!(custom.uvs, uvs);
+    let mesh = MeshInfo::from(custom);
+    assert_eq!(mesh.uvs, uvs);
+    winding_integrity(&mesh);
+}
+
+#[test]
+fn displacement() {
+    let layout = HexLayout::default();
+    let mesh = ColumnMeshBuilder::new(&layout, 2.0)
+        .with_subdivisions(3)
+        .build();
+    let mut displaced = mesh.clone();
+    displaced.displace(|_| 0.0);
+    assert_eq!(displaced.vertices, mesh.vertices);
+    displaced.displace(|p| p.y.mul_add(0.1, 0.2));
+    assert_ne!(displaced.vertices, mesh.vertices);
+    // Vertices sharing a position are kept together
+    for (i, v) in mesh.vertices.iter().enumerate() {
+        for (j, v2) in mesh.vertices.iter().enumerate().skip(i + 1) {
+            if v.distance(*v2) < 0.0001 {
+                assert!(displaced.vertices[i].abs_diff_eq(displaced.vertices[j], 0.0001));
+            }
+        }
+    }
+    // Recomputing normals of an untouched mesh keeps flat normals
+    let mut recomputed = mesh.clone();
+    recomputed.recompute_normals();
+    for (n, n2) in recomputed.normals.iter().zip(&mesh.normals) {
+        assert!(n.abs_diff_eq(*n2, 0.0001));
+    }
+    displaced.recompute_normals();
+    winding_integrity(&displaced);
+    mesh_integrity(displaced, mesh.vertices.len());
+}
+
+#[test]
+fn column_centered_pivot() {
+    let layout = HexLayout::flat();
+    let bounds = |mesh: &MeshInfo| {
+        mesh.vertices
+            .iter()
+            .fold([Vec3::MAX, Vec3::MIN], |[min, max], v| {
+                [min.min(*v), max.max(*v)]
+            })
+    };
+    let mesh = ColumnMeshBuilder::new(&layout, 10.0).build();
+    let centered = ColumnMeshBuilder::new(&layout, 10.0)
+        .centered_pivot()
+        .build();
+    let [min, max] = bounds(&centered);
+    assert!(min.abs_diff_eq(-max, 0.0001));
+    assert!((max.y - 5.0).abs() < 0.0001);
+    for (v, v2) in mesh.vertices.iter().zip(&centered.vertices) {
+        assert!((*v - *v2).abs_diff_eq(Vec3::Y * 5.0, 0.0001));
+    }
+    // Only the top face
+    let top = ColumnMeshBuilder::new(&layout, 10.0)
+        .without_bottom_face()
+        .with_multi_custom_sides_options([None; 6])
+        .centered_pivot()
+        .with_offset(Vec3::Y)
+        .build();
+    let [min, max] = bounds(&top);
+    assert!((min.y - 1.0).abs() < 0.0001);
+    assert!((max.y - 1.0).abs() < 0.0001);
+}
+
+#[test]
+fn column_parts() {
+    let layout = HexLayout::flat().with_scale(Vec2::new(2.0, 3.0));
+    let builder = ColumnMeshBuilder::new(&layout, 7.0)
+        .at(Hex::new(2, -3))
+        .with_subdivisions(3)
+        .centered_pivot()
+        .with_offset(Vec3::X);
+    let mesh = builder.clone().build();
+    let parts = builder.build_parts();
+    let top = parts.top.expect("Missing top face");
+    let bottom = parts.bottom.expect("Missing bottom face");
+    for part in [&top, &parts.sides, &bottom] {
+        winding_integrity(part);
+    }
+    assert_eq!(top.vertices.len(), 6);
+    assert_eq!(bottom.vertices.len(), 6);
+    assert_eq!(parts.sides.vertices.len(), 6 * 3 * 4);
+    let mut merged = parts.sides;
+    merged.merge_with(top);
+    merged.merge_with(bottom);
+    assert_eq!(merged.vertices, mesh.vertices);
+    assert_eq!(merged.normals, mesh.normals);
+    assert_eq!(merged.uvs, mesh.uvs);
+    assert_eq!(merged.indices, mesh.indices);
+    // Disabled faces
+    let parts = ColumnMeshBuilder::new(&layout, 7.0)
+        .without_top_face()
+        .without_bottom_face()
+        .build_parts();
+    assert!(parts.top.is_none());
+    assert!(parts.bottom.is_none());
+}
+
+#[test]
+fn column_sides_mask() {
+    let layout = HexLayout::pointy().with_scale(Vec2::new(2.0, 1.0));
+    let full = ColumnMeshBuilder::new(&layout, 3.0).build_parts().sides;
+    let dirs = [
+        EdgeDirection::ALL_DIRECTIONS[0],
+        EdgeDirection::ALL_DIRECTIONS[2],
+        EdgeDirection::ALL_DIRECTIONS[3],
+    ];
+    let mask: DirectionMask = dirs.into_iter().collect();
+    let sides = ColumnMeshBuilder::new(&layout, 3.0)
+        .with_sides(mask)
+        .build_parts()
+        .sides;
+    assert_eq!(sides.vertices.len(), 12);
+    // Remaining sides are unchanged
+    for (i, dir) in dirs.into_iter().enumerate() {
+        let range = |i: usize| i * 4..(i + 1) * 4;
+        let expected = range(dir.index() as usize);
+        assert_eq!(sides.vertices[range(i)], full.vertices[expected.clone()]);
+        assert_eq!(sides.normals[range(i)], full.normals[expected.clone()]);
+        assert_eq!(sides.uvs[range(i)], full.uvs[expected]);
+    }
+    let none = ColumnMeshBuilder::new(&layout, 3.0)
+        .with_sides(DirectionMask::EMPTY)
+        .build_parts();
+    assert!(none.sides.vertices.is_empty());
+    assert!(none.top.is_some());
+}
+
+#[test]
+fn column_corner_heights() {
+    let heights = [1.0, 2.0, 3.5, 3.0, 2.0, 0.5];
+    for layout in [
+        HexLayout::flat(),
+        HexLayout::pointy().with_scale(Vec2::new(2.0, -1.0)),
+    ] {
+        let corners = layout.corner_offsets();
+        let corner_height = |v: Vec3| {
+            corners
+                .iter()
+                .position(|c| c.abs_diff_eq(Vec2::new(v.x, v.z), 0.0001))
+                .map(|i| heights[i])
+        };
+        let parts = ColumnMeshBuilder::new(&layout, 10.0)
+            .with_corner_heights(heights)
+            .with_subdivisions(3)
+            .build_parts();
+        // Side vertices go up to the corner heights
+        for v in &parts.sides.vertices {
+            let height = corner_height(*v).expect("Side vertex is not on a corner");
+            assert!(v.y >= 0.0 && v.y <= height + 0.0001);
+        }
+        for (corner, height) in corners.into_iter().zip(heights) {
+            let top_corner = Vec3::new(corner.x, height, corner.y);
+            assert!(parts
+                .sides
+                .vertices
+                .iter()
+                .any(|v| v.abs_diff_eq(top_corner, 0.0001)));
+        }
+        winding_integrity(&parts.sides);
+        // Top face follows the corners and faces up
+        let top = parts.top.expect("Missing top face");
+        for (v, n) in top.vertices.iter().zip(&top.normals) {
+            let expected = corner_height(*v).expect("Top vertex is not on a corner");
+            assert!((v.y - expected).abs() < 0.0001);
+            assert!(n.is_normalized());
+            assert!(n.y > 0.0);
+        }
+        winding_integrity(&top);
+        // Uniform corner heights match the regular column
+        let flat = ColumnMeshBuilder::new(&layout, 2.0)
+            .with_subdivisions(2)
+            .build();
+        let uniform = ColumnMeshBuilder::new(&layout, 10.0)
+            .with_subdivisions(2)
+            .with_corner_heights([2.0; 6])
+            .build();
+        assert_eq!(flat.indices, uniform.indices);
+        for (a, b) in flat.vertices.iter().zip(&uniform.vertices) {
+            assert!(a.abs_diff_eq(*b, 0.0001));
+        }
+        for (a, b) in flat.normals.iter().zip(&uniform.normals) {
+            assert!(a.abs_diff_eq(*b, 0.0001));
+        }
+        // Hollow sloped column
+        let tube = ColumnMeshBuilder::new(&layout, 10.0)
+            .with_corner_heights(heights)
+            .with_wall_thickness(0.1)
+            .build_parts();
+        let rim = tube.top.expect("Missing top rim");
+        assert!(rim.normals.iter().all(|n| n.y > 0.0));
+        winding_integrity(&rim);
+    }
+}
+
+#[test]
+fn column_uv_regions() {
+    let layout = HexLayout::default();
+    let cell = |i: u8| Rect {
+        min: Vec2::new(f32::from(i) / 8.0, 0.0),
+        max: Vec2::new(f32::from(i + 1) / 8.0, 1.0),
+    };
+    let in_rect = |uv: &Vec2, rect: Rect| {
+        uv.cmpge(rect.min - 0.0001).all() && uv.cmple(rect.max + 0.0001).all()
+    };
+    let regions = [0, 1, 2, 3, 4, 5].map(cell);
+    let parts = ColumnMeshBuilder::new(&layout, 2.0)
+        .with_subdivisions(2)
+        .with_side_uv_regions(regions)
+        .with_caps_uv_regions(cell(6), cell(7))
+        .build_parts();
+    // Each side has 2 subdivisions of 4 vertices
+    for (side, uvs) in parts.sides.uvs.chunks_exact(8).enumerate() {
+        assert!(uvs.iter().all(|uv| in_rect(uv, regions[side])));
+    }
+    let [top, bottom] = [parts.top, parts.bottom].map(|m| m.expect("Missing cap"));
+    assert!(top.uvs.iter().all(|uv| in_rect(uv, cell(6))));
+    assert!(bottom.uvs.iter().all(|uv| in_rect(uv, cell(7))));
+    // Disabled sides stay disabled
+    let mesh = ColumnMeshBuilder::new(&layout, 2.0)
+        .with_sides(EdgeDirection::FLAT_TOP)
+        .with_side_uv_regions(regions)
+        .build_parts();
+    assert_eq!(mesh.sides.vertices.len(), 4);
+}
+
+#[test]
+fn inset_per_vertex() {
+    let layout = HexLayout::flat().with_scale(Vec2::new(2.0, 1.0));
+    let face = Hexagon::center_aligned(&layout);
+    for scale in [0.2, -0.1] {
+        let expected = face
+            .clone()
+            .inset(InsetScaleMode::SmallestEdge, scale, true);
+        let mesh = face.clone().inset_per_vertex([scale; 6], true);
+        assert_eq!(mesh.vertices, expected.vertices);
+        assert_eq!(mesh.uvs, expected.uvs);
+        assert_eq!(mesh.indices, expected.indices);
+    }
+    // Uneven bevel
+    let face = Hexagon::center_aligned(&HexLayout::flat());
+    let scales = [0.05, 0.1, 0.2, 0.3, 0.4, 0.05];
+    let mesh = face.clone().inset_per_vertex(scales, false);
+    let center = face.centroid();
+    let inset_dist: Vec<f32> = (0..6)
+        .map(|i| mesh.vertices[i].distance(mesh.vertices[i + 6]))
+        .collect();
+    for i in 0..6 {
+        assert!(mesh.vertices[i + 6].distance(center) < mesh.vertices[i].distance(center));
+    }
+    assert!(inset_dist.windows(2).take(4).all(|w| w[0] < w[1]));
+    winding_integrity(&mesh);
+    // Inset mode
+    let expected = face.clone().inset_per_vertex(scales, true);
+    let mesh = face.inset(InsetScaleMode::PerVertex(scales), 0.0, true);
+    assert_eq!(mesh.vertices, expected.vertices);
+    assert_eq!(mesh.indices, expected.indices);
+    let mesh = PlaneMeshBuilder::new(&HexLayout::flat())
+        .with_inset_options(InsetOptions {
+            keep_inner_face: true,
+            scale: 0.0,
+            mode: InsetScaleMode::PerVertex(scales),
+        })
+        .build();
+    assert_eq!(mesh.vertices, expected.vertices);
+    assert_eq!(mesh.indices, expected.indices);
+    // Mixed signs, the edges with a single sign face the right way
+    let scales = [0.2, 0.2, 0.2, -0.1, -0.1, -0.1];
+    let face = Hexagon::center_aligned(&HexLayout::flat());
+    let mesh = face.inset_per_vertex(scales, false);
+    for (i, quad) in mesh.indices.chunks_exact(6).enumerate() {
+        if scales[i] * scales[(i + 1) % 6] > 0.0 {
+            winding_integrity(&MeshInfo {
+                indices: quad.to_vec(),
+                ..mesh.clone()
+            });
+        }
+    }
+}
+
+#[test]
+fn column_bevel() {
+    for layout in [
+        HexLayout::flat(),
+        HexLayout::pointy().with_scale(Vec2::new(2.0, -1.0)),
+    ] {
+        let size = 0.2;
+        let parts = ColumnMeshBuilder::new(&layout, 3.0)
+            .with_bevel(size)
+            .with_subdivisions(2)
+            .build_parts();
+        // Sides stop below the top face
+        let sides_top = parts.sides.vertices.iter().map(|v| v.y).fold(0.0, f32::max);
+        assert!((sides_top - 3.0 + size).abs() < 0.0001);
+        winding_integrity(&parts.sides);
+        let top = parts.top.expect("Missing top face");
+        let corners = layout.corner_offsets();
+        for (v, n) in top.vertices.iter().zip(&top.normals) {
+            let p = Vec2::new(v.x, v.z);
+            if (v.y - 3.0).abs() < 0.0001 {
+                // Inset top face vertex
+                assert!(corners.iter().all(|c| !c.abs_diff_eq(p, 0.0001)));
+            } else {
+                // Chamfer bottom vertex, on a corner
+                assert!((v.y - 3.0 + size).abs() < 0.0001);
+                assert!(corners.iter().any(|c| c.abs_diff_eq(p, 0.0001)));
+            }
+            assert!(n.is_normalized());
+            assert!(n.y > 0.0);
+        }
+        winding_integrity(&top);
+        // Smooth chamfer normals are shared by the adjacent quads
+        for corner in corners {
+            let normals: Vec<_> = top
+                .vertices
+                .iter()
+                .zip(&top.normals)
+                .filter(|(v, _)| Vec2::new(v.x, v.z).abs_diff_eq(corner, 0.0001))
+                .map(|(_, n)| *n)
+                .collect();
+            assert_eq!(normals.len(), 2);
+            assert!(normals[0].abs_diff_eq(normals[1], 0.0001));
+        }
+        // No bevel without top face
+        let mesh = ColumnMeshBuilder::new(&layout, 3.0)
+            .with_bevel(size)
+            .without_top_face()
+            .build();
+        let max_height = mesh.vertices.iter().map(|v| v.y).fold(0.0, f32::max);
+        assert!((max_height - 3.0).abs() < 0.0001);
+    }
+}
+
+#[test]
+fn merge_all() {
+    let layout = HexLayout::default();
+    let meshes: Vec<_> = Hex::ZERO
+        .range(3)
+        .map(|h| ColumnMeshBuilder::new(&layout, 2.0).at(h).build())
+        .collect();
+    let mut expected = MeshInfo::default();
+    for mesh in meshes.clone() {
+        expected.merge_with(mesh);
+    }
+    let merged = MeshInfo::merge_all(meshes);
+    assert_eq!(merged.vertices, expected.vertices);
+    assert_eq!(merged.normals, expected.normals);
+    assert_eq!(merged.uvs, expected.uvs);
+    assert_eq!(merged.indices, expected.indices);
+    mesh_integrity(merged, expected.vertices.len());
+    // Empty
+    let empty = MeshInfo::merge_all([]);
+    assert!(empty.vertices.is_empty());
+    assert!(empty.indices.is_empty());
+}
+
+#[test]
+fn plane_for_hexes() {
+    let layout = HexLayout::pointy()
+        .with_origin(Vec2::new(3.0, -1.0))
+        .with_scale(Vec2::new(2.0, 1.0));
+    let uv = UVOptions::new().with_scale_factor(Vec2::splat(0.5));
+    let hexes: Vec<_> = Hex::ZERO.range(2).collect();
+    let expected = MeshInfo::merge_all(hexes.iter().map(|h| {
+        PlaneMeshBuilder::new(&layout)
+            .with_uv_options(uv)
+            .at(*h)
+            .build()
+    }));
+    let mesh = PlaneMeshBuilder::new(&layout)
+        .with_uv_options(uv)
+        .at(Hex::new(5, 5))
+        .build_for_hexes(hexes.iter().copied());
+    assert_eq!(mesh.indices, expected.indices);
+    assert_eq!(mesh.uvs, expected.uvs);
+    for (v, e) in mesh.vertices.iter().zip(&expected.vertices) {
+        assert!(v.abs_diff_eq(*e, 0.0001));
+    }
+    mesh_integrity(mesh, expected.vertices.len());
+    let mesh = PlaneMeshBuilder::for_hexes(&layout, hexes);
+    assert_eq!(mesh.vertices.len(), 19 * 6);
+    winding_integrity(&mesh);
+}
+
+#[test]
+fn plane_subdivisions() {
+    for scale in [Vec2::ONE, Vec2::new(-2.0, 1.0)] {
+        let layout = HexLayout::pointy().with_scale(scale);
+        let plane = PlaneMeshBuilder::new(&layout).build();
+        let same = PlaneMeshBuilder::new(&layout).with_subdivisions(0).build();
+        assert_eq!(plane.vertices, same.vertices);
+        assert_eq!(plane.indices, same.indices);
+        for levels in 1..4 {
+            let mesh = PlaneMeshBuilder::new(&layout)
+                .with_subdivisions(levels)
+                .build();
+            let size = 2_usize.pow(levels);
+            assert_eq!(mesh.indices.len(), 6 * size * size * 3);
+            winding_integrity(&mesh);
+            // Corners keep their uvs
+            for (corner, uv) in plane.vertices.iter().zip(&plane.uvs) {
+                let i = mesh
+                    .vertices
+                    .iter()
+                    .position(|v| v.abs_diff_eq(*corner, 0.0001))
+                    .expect("Missing corner");
+                assert!(mesh.uvs[i].abs_diff_eq(*uv, 0.0001));
+            }
+            // Vertices are shared
+            for (i, v) in mesh.vertices.iter().enumerate() {
+                assert!(mesh.vertices[i + 1..]
+                    .iter()
+                    .all(|other| !other.abs_diff_eq(*v, 0.0001)));
+            }
+            mesh_integrity(mesh, 3 * size * (size + 1) + 1);
+        }
+    }
+}
+
+#[test]
+fn max_plane_subdivisions() {
+    let layout = HexLayout::default();
+    let mesh = PlaneMeshBuilder::new(&layout).with_subdivisions(7).build();
+    assert_eq!(mesh.vertices.len(), 3 * 128 * 129 + 1);
+    let max = mesh.indices.iter().copied().max().expect("Missing indices");
+    assert_eq!(usize::from(max), mesh.vertices.len() - 1);
+}
+
+#[test]
+#[should_panic(expected = "Subdivided hexagon has too many vertices")]
+fn too_many_plane_subdivisions() {
+    let layout = HexLayout::default();
+    let _mesh = PlaneMeshBuilder::new(&layout).with_subdivisions(8).build();
+}
+
+#[test]
+fn outline_thickness() {
+    for scale in [Vec2::ONE, Vec2::new(3.0, -2.0)] {
+        let layout = HexLayout::flat().with_scale(scale);
+        let corners = layout.center_aligned_hex_corners();
+        let outer_corner = |mesh: &MeshInfo, corner: Vec2| {
+            mesh.vertices.iter().any(|v| {
+                let p = Vec2::new(v.x, v.z);
+                p.abs_diff_eq(corner, 0.0001)
+            })
+        };
+        // Default outline is 10% larger
+        let mesh = OutlineMeshBuilder::new(&layout).build();
+        winding_integrity(&mesh);
+        for corner in corners {
+            assert!(outer_corner(&mesh, corner));
+            assert!(outer_corner(&mesh, corner * 1.1));
+        }
+        mesh_integrity(mesh, 24);
+        // Custom thickness added to the scale
+        let mesh = OutlineMeshBuilder::new(&layout).with_thickness(0.5).build();
+        winding_integrity(&mesh);
+        for corner in corners {
+            assert!(outer_corner(
+                &mesh,
+                corner / scale * (scale + scale.signum() * 0.5)
+            ));
+        }
+    }
+}
+
+#[test]
+fn outline_region() {
+    let layout = HexLayout::pointy().with_scale(Vec2::new(2.0, 1.0));
+    // A single hex has a full outline
+    let single = OutlineMeshBuilder::for_region(&layout, &HashSet::from([Hex::ZERO]));
+    let expected = OutlineMeshBuilder::new(&layout).build();
+    assert_eq!(single.vertices, expected.vertices);
+    assert_eq!(single.indices, expected.indices);
+    // Shared edges are not outlined
+    let pair = HashSet::from([Hex::ZERO, Hex::new(1, 0)]);
+    let mesh = OutlineMeshBuilder::for_region(&layout, &pair);
+    assert_eq!(mesh.vertices.len(), 10 * 4);
+    winding_integrity(&mesh);
+    // Holes are outlined
+    let ring: HashSet<_> = Hex::ZERO.ring(1).collect();
+    let mesh = OutlineMeshBuilder::new(&layout)
+        .with_thickness(0.1)
+        .build_for_region(&ring);
+    winding_integrity(&mesh);
+    mesh_integrity(mesh, 6 * 4 * 4);
+}
+
+#[test]
+fn outline_region_inward_corner() {
+    let layout = HexLayout::flat().with_hex_size(2.0);
+    // The missing ring hex makes the region boundary turn inward
+    let region: HashSet<_> = Hex::ZERO
+        .range(1)
+        .filter(|h| *h != Hex::ZERO.neighbor(EdgeDirection::FLAT_TOP))
+        .collect();
+    for builder in [
+        OutlineMeshBuilder::new(&layout),
+        OutlineMeshBuilder::new(&layout).with_thickness(0.3),
+    ] {
+        let mesh = builder.build_for_region(&region);
+        winding_integrity(&mesh);
+        let quads: Vec<[Vec2; 4]> = mesh
+            .vertices
+            .chunks_exact(4)
+            .map(|q| [q[0], q[1], q[2], q[3]].map(|v| Vec2::new(v.x, v.z)))
+            .collect();
+        let side = |a: Vec2, b: Vec2, p: Vec2| (b - a).perp_dot(p - a);
+        for (i, quad) in quads.iter().enumerate() {
+            // The quad sides go from an outer corner to an inner corner
+            for [outer, inner, others] in [[0, 3, 1], [1, 2, 0]] {
+                let [outer, inner, other] = [quad[outer], quad[inner], quad[others]];
+                // The side is shared with exactly one other quad
+                let shared: Vec<_> = quads
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, q)| {
+                        *j != i
+                            && q.iter().any(|v| v.abs_diff_eq(outer, 1e-4))
+                            && q.iter().any(|v| v.abs_diff_eq(inner, 1e-4))
+                    })
+                    .collect();
+                assert_eq!(shared.len(), 1);
+                // The quads are on both sides of the shared side
+                let (_, neighbor) = shared[0];
+                let sign = side(outer, inner, other);
+                for v in neighbor {
+                    assert!(sign * side(outer, inner, *v) <= 1e-4);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn checked_merge() {
+    let layout = HexLayout::default();
+    let plane = PlaneMeshBuilder::new(&layout).build();
+    let mut mesh = plane.clone();
+    assert_eq!(mesh.try_merge(plane.clone()), Ok(()));
+    assert_eq!(mesh.vertices.len(), 12);
+    mesh_integrity(mesh.clone(), 12);
+    let mut invalid = plane.clone();
+    invalid.normals.pop();
+    let before = mesh.clone();
+    assert_eq!(
+        mesh.try_merge(invalid.clone()),
+        Err(MeshMergeError::MismatchedAttributes)
+    );
+    assert_eq!(mesh.vertices, before.vertices);
+    assert_eq!(
+        invalid.try_merge(plane),
+        Err(MeshMergeError::MismatchedAttributes)
+    );
+}
+
+#[test]
+fn checked_merge_overflow() {
+    let layout = HexLayout::default();
+    let plane = PlaneMeshBuilder::new(&layout).build();
+    let mut big = MeshInfo {
+        vertices: vec![Vec3::ZERO; 65_534],
+        normals: vec![Vec3::Y; 65_534],
+        uvs: vec![Vec2::ZERO; 65_534],
+        indices: vec![],
+    };
+    assert_eq!(
+        big.try_merge(plane.clone()),
+        Err(MeshMergeError::TooManyVertices)
+    );
+    big.vertices.truncate(65_530);
+    big.normals.truncate(65_530);
+    big.uvs.truncate(65_530);
+    assert_eq!(big.try_merge(plane), Ok(()));
+    assert_eq!(big.vertices.len(), usize::from(u16::MAX) + 1);
+}
+
+#[test]
+fn u32_indices_merge() {
+    let layout = HexLayout::default();
+    let column = ColumnMeshBuilder::new(&layout, 1.0).build();
+    let count = usize::from(u16::MAX) / column.vertices.len() + 1;
+    let mesh = MeshInfo32::merge_all(std::iter::repeat_n(column.clone(), count + 1));
+    assert!(mesh.vertices.len() > usize::from(u16::MAX));
+    assert!(mesh.indices.iter().any(|i| *i > u32::from(u16::MAX)));
+    assert_eq!(mesh.indices.len(), column.indices.len() * (count + 1));
+    assert!(mesh
+        .indices
+        .iter()
+        .all(|i| (*i as usize) < mesh.vertices.len()));
+    let wide = column.clone().into_u32_indices();
+    assert_eq!(wide.vertices, column.vertices);
+    assert!(wide
+        .indices
+        .iter()
+        .copied()
+        .eq(column.indices.iter().copied().map(u32::from)));
+}
+
+#[test]
+fn uv_flipping() {
+    let layout = HexLayout::flat().with_scale(Vec2::new(2.0, 1.0));
+    let uv_bounds = |uvs: &[Vec2]| {
+        uvs.iter().fold([Vec2::MAX, Vec2::MIN], |[min, max], uv| {
+            [min.min(*uv), max.max(*uv)]
+        })
+    };
+    let mesh = PlaneMeshBuilder::new(&layout).build();
+    let options = UVOptions::new().flip_u().flip_v();
+    let flipped = PlaneMeshBuilder::new(&layout)
+        .with_uv_options(options)
+        .build();
+    let [min, max] = uv_bounds(&mesh.uvs);
+    let [flipped_min, flipped_max] = uv_bounds(&flipped.uvs);
+    // The flipped UVs cover the same region, mirrored
+    assert!(flipped_min.abs_diff_eq(Vec2::ONE - max, 0.0001));
+    assert!(flipped_max.abs_diff_eq(Vec2::ONE - min, 0.0001));
+    for (uv, flipped_uv) in mesh.uvs.iter().zip(&flipped.uvs) {
+        assert!(flipped_uv.abs_diff_eq(Vec2::ONE - *uv, 0.0001));
+        assert!(flipped_uv.cmpge(Vec2::ZERO).all() && flipped_uv.cmple(Vec2::ONE).all());
+    }
+    // Flipping twice is a no-op
+    let mut uvs = flipped.uvs;
+    options.alter_uvs(&mut uvs);
+    for (uv, uv2) in mesh.uvs.iter().zip(&uvs) {
+        assert!(uv.abs_diff_eq(*uv2, 0.0001));
+    }
+}
+
+#[test]
+fn triplanar_uvs() {
+    let layout = HexLayout::pointy().with_origin(Vec2::new(3.0, -1.0));
+    let scale = 0.5;
+    let mesh = ColumnMeshBuilder::new(&layout, 4.0)
+        .at(Hex::new(2, -1))
+        .with_triplanar_uvs(scale)
+        .build();
+    for ((v, n), uv) in mesh.vertices.iter().zip(&mesh.normals).zip(&mesh.uvs) {
+        let expected = if n.y.abs() > 0.9 {
+            Vec2::new(v.x, v.z)
+        } else if n.x.abs() >= n.z.abs() {
+            Vec2::new(v.z, v.y)
+        } else {
+            Vec2::new(v.x, v.y)
+        };
+        assert_eq!(*uv, expected * scale);
+    }
+    // Facing side walls of adjacent columns share the same uvs
+    let other = ColumnMeshBuilder::new(&layout, 8.0)
+        .at(Hex::new(3, -1))
+        .with_triplanar_uvs(scale)
+        .build();
+    let mut shared = 0;
+    for ((v, n), uv) in mesh.vertices.iter().zip(&mesh.normals).zip(&mesh.uvs) {
+        for ((v2, n2), uv2) in other.vertices.iter().zip(&other.normals).zip(&other.uvs) {
+            if v.abs_diff_eq(*v2, 0.0001) && n.dot(*n2) < -0.99 {
+                assert!(uv.abs_diff_eq(*uv2, 0.0001));
+                shared += 1;
+            }
+        }
+    }
+    assert_eq!(shared, 2);
+}
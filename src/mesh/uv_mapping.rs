@@ -31,6 +31,10 @@ pub struct UVOptions {
     /// Flag indicating whether to flip the UV
     /// * the `x` value applies to `u`
     /// * the `y` value applies to `v`
+    ///
+    /// The flip mirrors the coordinates around the center of the UV space
+    /// (`0.5`), before any other operation, so UVs in the `[0, 1]` range stay
+    /// in that range.
     pub flip: BVec2,
     /// The offset value of the UV coordinates.
     /// * the `x` value applies to `u`
@@ -99,6 +99,49 @@ impl HexOrientationData {
         }
     }
 
+    #[must_use]
+    /// Constructs forward and inverse matrices for a grid rotated by an
+    /// arbitrary `angle_offset` in radians.
+    ///
+    /// The angle is the direction of the first hexagon corner (see
+    /// [`HexLayout::corner_offsets`]), so:
+    /// * [`Self::flat`] matches an angle of `0`
+    /// * [`Self::pointy`] matches an angle of `-PI / 6`
+    ///
+    /// Any other angle rotates the [`Self::flat`] grid counter clockwise by
+    /// `angle_offset`, which is what a flat [`HexLayout`] with a
+    /// [`rotation`](crate::HexLayout::rotation) of `angle_offset` applies. Prefer
+    /// [`HexLayout::with_rotation`] to work with rotated grids, this
+    /// constructor is useful to convert coordinates without a layout.
+    ///
+    /// # Note
+    ///
+    /// The [`Self::flat`] and [`Self::pointy`] presets are `const` and use
+    /// exact precomputed values, matching this constructor up to floating
+    /// point errors.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use hexx::{*, orientation::HexOrientationData};
+    /// let data = HexOrientationData::from_angle(0.3);
+    /// let layout = HexLayout::flat().with_rotation(0.3);
+    /// let pos = layout.hex_to_world_pos(hex(2, -1));
+    /// assert!(pos.abs_diff_eq(data.forward(hex(2, -1).as_vec2()), 1e-5));
+    /// assert_eq!(Hex::round(data.inverse(pos).to_array()), hex(2, -1));
+    /// ```
+    ///
+    /// [`HexLayout::corner_offsets`]: crate::HexLayout::corner_offsets
+    /// [`HexLayout`]: crate::HexLayout
+    /// [`HexLayout::with_rotation`]: crate::HexLayout::with_rotation
+    pub fn from_angle(angle_offset: f32) -> Self {
+        let forward_matrix = Mat2::from_angle(angle_offset) * FLAT_ORIENTATION.forward_matrix;
+        Self {
+            forward_matrix,
+            inverse_matrix: forward_matrix.inverse(),
+        }
+    }
+
     #[must_use]
     #[inline]
     /// Applies the orientation `forward_matrix` to a point `p`
@@ -160,6 +203,8 @@ impl std::ops::Not for HexOrientation {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Hex;
+    use std::f32::consts::FRAC_PI_6;
 
     #[test]
     fn matrix_validity() {
@@ -167,4 +212,32 @@ mod tests {
             assert_eq!(data.inverse_matrix, data.forward_matrix.inverse());
         }
     }
+
+    #[test]
+    fn from_angle() {
+        let [flat, pointy] = [0.0, -FRAC_PI_6].map(HexOrientationData::from_angle);
+        assert!(flat
+            .forward_matrix
+            .abs_diff_eq(FLAT_ORIENTATION.forward_matrix, 0.0001));
+        assert!(flat
+            .inverse_matrix
+            .abs_diff_eq(FLAT_ORIENTATION.inverse_matrix, 0.0001));
+        assert!(pointy
+            .forward_matrix
+            .abs_diff_eq(POINTY_ORIENTATION.forward_matrix, 0.0001));
+        assert!(pointy
+            .inverse_matrix
+            .abs_diff_eq(POINTY_ORIENTATION.inverse_matrix, 0.0001));
+        for angle in [0.1, 1.0, -2.5, 4.0] {
+            let data = HexOrientationData::from_angle(angle);
+            for hex in Hex::ZERO.range(5) {
+                let pos = data.forward(hex.as_vec2());
+                // Rotated from the flat orientation
+                let expected =
+                    Vec2::from_angle(angle).rotate(FLAT_ORIENTATION.forward(hex.as_vec2()));
+                assert!(pos.abs_diff_eq(expected, 0.0001));
+                assert_eq!(Hex::round(data.inverse(pos).to_array()), hex);
+            }
+        }
+    }
 }
@@ -0,0 +1,131 @@
+use crate::{EdgeDirection, Hex, VertexDirection};
+
+/// Common trait for types which can be rotated by 60 degrees steps, like
+/// [`Hex`] coordinates and directions.
+///
+/// This allows to consistently rotate composite values, like `(Hex, T)`
+/// tiles with orientation sensitive data.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// // A tile with a coordinate and a facing direction
+/// let tile = (hex(2, 0), EdgeDirection::FLAT_TOP);
+/// let rotated = tile.rotate_cw(1);
+/// assert_eq!(
+///     rotated,
+///     (hex(2, 0).rotate_cw(1), EdgeDirection::FLAT_TOP_RIGHT)
+/// );
+/// // Rotating a whole shape
+/// let shape = [
+///     (hex(1, 0), VertexDirection::FLAT_RIGHT),
+///     (hex(0, 1), VertexDirection::FLAT_LEFT),
+/// ];
+/// let rotated = shape.map(|tile| Rotatable::rotate_ccw(tile, 2));
+/// ```
+pub trait Rotatable: Sized {
+    /// Rotates `self` clockwise by `steps` (by `60 * steps` degrees)
+    #[must_use]
+    fn rotate_cw(self, steps: u32) -> Self;
+
+    /// Rotates `self` counter clockwise by `steps` (by `-60 * steps` degrees)
+    #[must_use]
+    fn rotate_ccw(self, steps: u32) -> Self {
+        self.rotate_cw(6 - steps % 6)
+    }
+}
+
+impl Rotatable for Hex {
+    fn rotate_cw(self, steps: u32) -> Self {
+        self.rotate_cw(steps)
+    }
+
+    fn rotate_ccw(self, steps: u32) -> Self {
+        self.rotate_ccw(steps)
+    }
+}
+
+impl Rotatable for EdgeDirection {
+    #[allow(clippy::cast_possible_truncation)]
+    fn rotate_cw(self, steps: u32) -> Self {
+        self.rotate_cw((steps % 6) as u8)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn rotate_ccw(self, steps: u32) -> Self {
+        self.rotate_ccw((steps % 6) as u8)
+    }
+}
+
+impl Rotatable for VertexDirection {
+    #[allow(clippy::cast_possible_truncation)]
+    fn rotate_cw(self, steps: u32) -> Self {
+        self.rotate_cw((steps % 6) as u8)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn rotate_ccw(self, steps: u32) -> Self {
+        self.rotate_ccw((steps % 6) as u8)
+    }
+}
+
+#[cfg(feature = "grid")]
+impl Rotatable for crate::GridEdge {
+    #[allow(clippy::cast_possible_truncation)]
+    fn rotate_cw(self, steps: u32) -> Self {
+        Self {
+            origin: self.origin.rotate_cw(steps),
+            direction: self.direction.rotate_cw((steps % 6) as u8),
+        }
+    }
+}
+
+#[cfg(feature = "grid")]
+impl Rotatable for crate::GridVertex {
+    #[allow(clippy::cast_possible_truncation)]
+    fn rotate_cw(self, steps: u32) -> Self {
+        Self {
+            origin: self.origin.rotate_cw(steps),
+            direction: self.direction.rotate_cw((steps % 6) as u8),
+        }
+    }
+}
+
+impl<A: Rotatable, B: Rotatable> Rotatable for (A, B) {
+    fn rotate_cw(self, steps: u32) -> Self {
+        (self.0.rotate_cw(steps), self.1.rotate_cw(steps))
+    }
+
+    fn rotate_ccw(self, steps: u32) -> Self {
+        (self.0.rotate_ccw(steps), self.1.rotate_ccw(steps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistent_rotations() {
+        for steps in 0..13 {
+            for dir in EdgeDirection::ALL_DIRECTIONS {
+                let rotated = Rotatable::rotate_cw(dir, steps);
+                assert_eq!(Hex::from(rotated), Hex::from(dir).rotate_cw(steps));
+                let rotated = Rotatable::rotate_ccw(dir, steps);
+                assert_eq!(Hex::from(rotated), Hex::from(dir).rotate_ccw(steps));
+            }
+            for dir in VertexDirection::ALL_DIRECTIONS {
+                let rotated = Rotatable::rotate_cw(dir, steps);
+                assert_eq!(Hex::from(rotated), Hex::from(dir).rotate_cw(steps));
+                let rotated = Rotatable::rotate_ccw(dir, steps);
+                assert_eq!(Hex::from(rotated), Hex::from(dir).rotate_ccw(steps));
+            }
+            let tile = (Hex::new(3, -1), EdgeDirection::FLAT_TOP);
+            assert_eq!(
+                Rotatable::rotate_ccw(Rotatable::rotate_cw(tile, steps), steps),
+                tile
+            );
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{hex::ExactSizeHexIterator, Hex};
+use crate::{hex::ExactSizeHexIterator, Hex, HexOrientation};
 
 /// Parallelogram shape parameters.
 ///
@@ -68,8 +68,26 @@ impl Triangle {
         triangle(self.size)
     }
 }
+
 /// Generates a triangle with a custom `size`
 ///
+/// The triangle is anchored at [`Hex::ZERO`] and its three corners are
+/// `(0, 0)`, `(size, 0)` and `(0, size)`: it contains every coordinate with
+/// positive `x` and `y` and a distance to [`Hex::ZERO`] of at most `size`.
+/// Its edges follow the `x`, `y` and `z` axes, for a total of
+/// `(size + 1) * (size + 2) / 2` coordinates.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let coords: Vec<_> = shapes::triangle(2).collect();
+/// assert_eq!(coords.len(), 6);
+/// assert!(coords.contains(&hex(2, 0)));
+/// assert!(coords.contains(&hex(0, 2)));
+/// assert!(coords.contains(&hex(1, 1)));
+/// ```
+///
 /// # Note
 ///
 /// To offset the map, apply the offset to each `Item` of the returned iterator
@@ -83,6 +101,14 @@ pub fn triangle(size: u32) -> impl ExactSizeIterator<Item = Hex> {
     }
 }
 
+/// Generates a straight line of coordinates from `a` to `b`, both included.
+///
+/// Equivalent to [`Hex::line_to`]
+#[must_use]
+pub fn line(a: Hex, b: Hex) -> impl ExactSizeIterator<Item = Hex> {
+    a.line_to(b)
+}
+
 /// Hexagon shape parameters.
 ///
 /// Calling `coords` will return coordinates in that shape.
@@ -120,6 +146,50 @@ pub fn hexagon(center: Hex, radius: u32) -> impl ExactSizeIterator<Item = Hex> {
     center.range(radius)
 }
 
+/// Generates a hollow hexagon around `center` with a custom `radius`,
+/// containing only the coordinates at exactly `radius` distance.
+///
+/// Equivalent to [`Hex::ring`]
+#[must_use]
+pub fn hexagon_ring(center: Hex, radius: u32) -> impl ExactSizeIterator<Item = Hex> {
+    center.ring(radius)
+}
+
+/// Generates a thick hexagonal ring around `center`, containing every
+/// coordinate with a distance to `center` between `inner_radius` and
+/// `outer_radius`, both included.
+///
+/// The coordinates are ordered ring by ring, see [`Hex::spiral_range`]. An
+/// `inner_radius` of zero produces the full [`hexagon`] and an `inner_radius`
+/// greater than `outer_radius` produces no coordinates.
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let coords: Vec<_> = shapes::hexagon_annulus(Hex::ZERO, 2, 3).collect();
+/// assert_eq!(coords.len(), 12 + 18);
+/// ```
+#[must_use]
+#[doc(alias = "thick_ring")]
+pub fn hexagon_annulus(
+    center: Hex,
+    inner_radius: u32,
+    outer_radius: u32,
+) -> impl ExactSizeIterator<Item = Hex> {
+    let count = if inner_radius > outer_radius {
+        0
+    } else if inner_radius == 0 {
+        Hex::range_count(outer_radius)
+    } else {
+        Hex::range_count(outer_radius) - Hex::range_count(inner_radius - 1)
+    };
+    ExactSizeHexIterator {
+        iter: center.spiral_range(inner_radius..=outer_radius),
+        count: count as usize,
+    }
+}
+
 /// Rombus shape parameters.
 ///
 /// Calling `coords` will return coordinates in that shape.
@@ -282,9 +352,59 @@ pub fn flat_rectangle([left, right, top, bottom]: [i32; 4]) -> impl ExactSizeIte
     }
 }
 
+/// Generates a solid rectangle with `min` and `max` as opposite corners.
+///
+/// The rows (for [pointy] hexagons) or columns (for [flat] hexagons) are
+/// staggered so the rectangle lines up visually with the given
+/// `orientation`. Each coordinate is yielded exactly once.
+///
+/// > See also [`pointy_rectangle`] and [`flat_rectangle`]
+///
+/// # Example
+///
+/// ```rust
+/// # use hexx::*;
+/// let min = hex(-2, -3);
+/// let max = hex(4, 5);
+/// let coords: Vec<_> = shapes::rectangle(min, max, HexOrientation::Pointy).collect();
+/// // 9 rows of 11 hexes
+/// assert_eq!(coords.len(), 9 * 11);
+/// assert!(coords.contains(&min));
+/// assert!(coords.contains(&max));
+/// ```
+///
+/// [pointy]: HexOrientation::Pointy
+/// [flat]: HexOrientation::Flat
+#[must_use]
+#[allow(clippy::cast_sign_loss)]
+pub fn rectangle(
+    min: Hex,
+    max: Hex,
+    orientation: HexOrientation,
+) -> impl ExactSizeIterator<Item = Hex> {
+    // Offset coordinates matching `pointy_rectangle` and `flat_rectangle`
+    let to_offset = move |h: Hex| match orientation {
+        HexOrientation::Pointy => [h.x + (h.y >> 1), h.y],
+        HexOrientation::Flat => [h.x, h.y + (h.x >> 1)],
+    };
+    let from_offset = move |[col, row]: [i32; 2]| match orientation {
+        HexOrientation::Pointy => Hex::new(col - (row >> 1), row),
+        HexOrientation::Flat => Hex::new(col, row - (col >> 1)),
+    };
+    let [[ax, ay], [bx, by]] = [to_offset(min), to_offset(max)];
+    let [left, right, top, bottom] = [ax.min(bx), ax.max(bx), ay.min(by), ay.max(by)];
+    let count = (right - left + 1) * (bottom - top + 1);
+    ExactSizeHexIterator {
+        iter: (top..=bottom)
+            .flat_map(move |row| (left..=right).map(move |col| from_offset([col, row]))),
+        count: count as usize,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn hexagon_test() {
@@ -295,11 +415,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn hexagon_ring_test() {
+        let center = Hex::new(3, -7);
+        for radius in 0..=30 {
+            let mut iter = hexagon_ring(center, radius);
+            assert_eq!(iter.len(), Hex::ring_count(radius));
+            assert!(iter.all(|h| h.unsigned_distance_to(center) == radius));
+        }
+    }
+
+    #[test]
+    fn hexagon_annulus_test() {
+        let center = Hex::new(3, -7);
+        for inner in 1..=15 {
+            for outer in inner..=30 {
+                let iter = hexagon_annulus(center, inner, outer);
+                let expected = hexagon(center, outer).len() - hexagon(center, inner - 1).len();
+                assert_eq!(iter.len(), expected);
+                let coords: HashSet<_> = iter.collect();
+                assert_eq!(coords.len(), expected);
+                assert!(coords.iter().all(|h| {
+                    let dist = h.unsigned_distance_to(center);
+                    dist >= inner && dist <= outer
+                }));
+            }
+        }
+        assert_eq!(
+            hexagon_annulus(center, 0, 4).len(),
+            hexagon(center, 4).len()
+        );
+        assert_eq!(hexagon_annulus(center, 5, 4).count(), 0);
+        assert_eq!(hexagon_annulus(center, 5, 4).len(), 0);
+    }
+
     #[test]
     fn triangle_test() {
         for range in 0..=30 {
             let iter = triangle(range);
             assert_eq!(iter.len(), iter.count());
+            let coords: HashSet<_> = triangle(range).collect();
+            assert_eq!(coords.len(), ((range + 1) * (range + 2) / 2) as usize);
+            assert!(coords
+                .iter()
+                .all(|h| h.x >= 0 && h.y >= 0 && h.ulength() <= range));
+        }
+    }
+
+    #[test]
+    fn line_test() {
+        let a = Hex::new(-3, 5);
+        for b in Hex::ZERO.range(10) {
+            let iter = line(a, b);
+            assert_eq!(iter.len(), a.unsigned_distance_to(b) as usize + 1);
+            assert!(iter.eq(a.line_to(b)));
         }
     }
 
@@ -339,6 +508,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rectangle_test() {
+        for orientation in [HexOrientation::Pointy, HexOrientation::Flat] {
+            for min in Hex::ZERO.range(6) {
+                for max in Hex::new(2, -1).range(6) {
+                    let iter = rectangle(min, max, orientation);
+                    let len = iter.len();
+                    let coords: HashSet<_> = iter.collect();
+                    assert_eq!(coords.len(), len);
+                    assert!(coords.contains(&min));
+                    assert!(coords.contains(&max));
+                    assert_eq!(rectangle(max, min, orientation).len(), len);
+                }
+            }
+        }
+        // 4 rows of 5 hexes
+        let iter = rectangle(Hex::ZERO, Hex::new(3, 3), HexOrientation::Pointy);
+        assert_eq!(iter.len(), 4 * 5);
+        // 4 columns of 5 hexes
+        let iter = rectangle(Hex::ZERO, Hex::new(3, 3), HexOrientation::Flat);
+        assert_eq!(iter.len(), 4 * 5);
+        let coords: HashSet<_> = iter.collect();
+        assert_eq!(coords, flat_rectangle([0, 3, 0, 4]).collect());
+    }
+
     #[test]
     fn flat_rectangle_test() {
         for left in -20..=20 {